@@ -7,6 +7,7 @@ use cortex_m::delay::Delay;
 
 use fugit::RateExtU32;
 use gc9a01a_driver::{FrameBuffer, Orientation, Region, GC9A01A};
+use gc9a01a_driver::geometry::{calculate_bounding_box, draw_polygon, get_coordinates};
 use panic_halt as _; // for using write! macro
 
 use embedded_hal::adc::OneShot;
@@ -29,17 +30,13 @@ use waveshare_rp2040_lcd_1_28::{
 
 use embedded_graphics::{
     image::{Image, ImageRaw},
-    mono_font::MonoTextStyleBuilder,
     pixelcolor::Rgb565,
     prelude::*,
-    primitives::{Circle, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, Triangle},
-    text::{Baseline, Text},
+    primitives::{Circle, PrimitiveStyle, PrimitiveStyleBuilder},
 };
 
 use profont::PROFONT_18_POINT;
 
-use libm::{cos, sin};
-
 use core::fmt::Write;
 use heapless::String; // Import the Write trait for using the write! macro
 
@@ -55,6 +52,10 @@ const DESIRED_FRAME_DURATION_US: u32 = 1_000_000 / 16;
 const ARROW_ROTATE_POINT_X: i32 = 240 / 2;
 const ARROW_ROTATE_POINT_Y: i32 = (240 / 10) * 8;
 
+// Fixed boxes for the numbers, so they stay centered as the digit count changes
+const WEST_TEXT_REGION: Region = Region { x: 29, y: 35, width: 48, height: 24 };
+const EAST_TEXT_REGION: Region = Region { x: 169, y: 35, width: 56, height: 24 };
+
 #[derive(Debug)]
 enum Mode {
     EXAMPLE,
@@ -240,25 +241,26 @@ fn main() -> ! {
         let mut west_number_str: String<32> = String::new(); // Create a heapless String with a capacity of 32
         write!(west_number_str, "{:.0}", measurement.converted_value).unwrap(); // Write the number into the string
 
-        let west_text_bounding_region = draw_text_with_background(
-            &mut framebuffer,
+        let west_text_bounding_region = framebuffer.draw_text_centered(
             &west_number_str,
-            Point::new(35, 35),
+            WEST_TEXT_REGION,
+            &PROFONT_18_POINT,
             Rgb565::BLACK,
+            Rgb565::WHITE,
         );
         display.store_region(west_text_bounding_region).unwrap();
 
         let mut east_number_str: String<32> = String::new(); // Create a heapless String with a capacity of 32
         write!(east_number_str, "{:.1}", measurement.calculated_average).unwrap(); // Write the number into the string
 
-        let east_text_bounding_region = draw_text_with_background(
-            &mut framebuffer,
+        let east_text_bounding_region = framebuffer.draw_text_centered(
             &east_number_str,
-            Point::new(173, 35),
+            EAST_TEXT_REGION,
+            &PROFONT_18_POINT,
             Rgb565::BLACK,
+            Rgb565::WHITE,
         );
 
-
         //Clear the background
         //let background_differences = background_framebuffer.diff_with(&framebuffer);
         //display.draw_iter(background_differences).unwrap();
@@ -316,41 +318,6 @@ fn map_value(input_value: f32, in_min: f32, in_max: f32, out_min: f32, out_max:
     mapped_output_value
 }
 
-fn draw_text_with_background(
-    framebuffer: &mut FrameBuffer,
-    text: &str,
-    position: Point,
-    text_color: Rgb565,
-) -> Region {
-    let character_style = MonoTextStyleBuilder::new()
-        .font(&PROFONT_18_POINT)
-        .text_color(text_color)
-        .build();
-
-    // Calculate the size of the text
-    let text_area = Rectangle::new(
-        position,
-        Size::new(
-            text.len() as u32 * PROFONT_18_POINT.character_size.width,
-            PROFONT_18_POINT.character_size.height,
-        ),
-    );
-
-    // Draw the text
-    Text::with_baseline(text, position, character_style, Baseline::Top)
-        .draw(framebuffer)
-        .unwrap();
-
-    // Return the bounding box
-    //Added 22 width on the Region to accomidate larger numbers
-    Region {
-        x: text_area.top_left.x as u16,
-        y: text_area.top_left.y as u16,
-        width: text_area.size.width + 22,
-        height: text_area.size.height,
-    }
-}
-
 /// Create an arrow image at a specified angle and position
 fn create_arrow(
     framebuffer: &mut FrameBuffer,
@@ -411,9 +378,9 @@ fn create_arrow(
     let primary_color = PrimitiveStyleBuilder::new().fill_color(red).build();
     let complementary_color = PrimitiveStyleBuilder::new().fill_color(red_9).build();
 
-    draw_polygon(framebuffer, &merged_points, primary_color);
-    draw_polygon(framebuffer, &left_points[0..4], complementary_color);
-    draw_polygon(framebuffer, &right_points[0..4], primary_color);
+    draw_polygon(framebuffer, &merged_points, primary_color).unwrap();
+    draw_polygon(framebuffer, &left_points[0..4], complementary_color).unwrap();
+    draw_polygon(framebuffer, &right_points[0..4], primary_color).unwrap();
 
     // Calculate the bounding box of the arrow
     let bounding_box = calculate_bounding_box(&merged_points, 10);
@@ -421,28 +388,6 @@ fn create_arrow(
     bounding_box
 }
 
-/// Draw a polygon on the frame buffer
-fn draw_polygon(framebuffer: &mut FrameBuffer, points: &[Point], style: PrimitiveStyle<Rgb565>) {
-    if points.len() < 3 {
-        return; // Not enough points to form a polygon
-    }
-
-    // Use fan triangulation from the first point
-    let first_point = points[0];
-    for i in 1..points.len() - 1 {
-        let triangle = Triangle::new(first_point, points[i], points[i + 1]).into_styled(style);
-        triangle.draw(framebuffer).unwrap();
-    }
-}
-
-// Helper function to calculate coordinates based on angle and radius
-fn get_coordinates(center: Point, radius: i32, angle: i32) -> Point {
-    let angle_rad = (angle as f32).to_radians() as f64;
-    let x = center.x + (radius as f32 * cos(angle_rad) as f32) as i32;
-    let y = center.y + (radius as f32 * sin(angle_rad) as f32) as i32;
-    Point::new(x, y)
-}
-
 /// Draws a circle on the frame buffer.
 fn draw_circle(framebuffer: &mut FrameBuffer, color: Rgb565, center: Point, radius: i32) {
     let style = PrimitiveStyleBuilder::new().fill_color(color).build();
@@ -467,37 +412,6 @@ fn create_button(framebuffer: &mut FrameBuffer, center_x: i32, center_y: i32) {
     );
 }
 
-/// Helper function to calculate the bounding box of a set of points with an optional padding.
-fn calculate_bounding_box(points: &[Point], padding: u16) -> Region {
-    let mut min_x = points[0].x as i32;
-    let mut max_x = points[0].x as i32;
-    let mut min_y = points[0].y as i32;
-    let mut max_y = points[0].y as i32;
-
-    for point in points.iter().skip(1) {
-        if point.x < min_x {
-            min_x = point.x;
-        }
-        if point.x > max_x {
-            max_x = point.x;
-        }
-        if point.y < min_y {
-            min_y = point.y;
-        }
-        if point.y > max_y {
-            max_y = point.y;
-        }
-    }
-
-    let padding = padding as i32;
-    Region {
-        x: (min_x - padding) as u16,
-        y: (min_y - padding) as u16,
-        width: (max_x - min_x + 2 * padding) as u32,
-        height: (max_y - min_y + 2 * padding) as u32,
-    }
-}
-
 ///known resistor for voltage divider
 ///The known resistor is connected between the positive and the adc_pin_26.
 ///The unknown resistor (return value) is connected between adc_pin_26 and ground.
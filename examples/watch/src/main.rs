@@ -6,6 +6,7 @@ mod waveshare_rp2040_lcd_1_28;
 use cortex_m::delay::Delay;
 use fugit::RateExtU32;
 use gc9a01a_driver::{FrameBuffer, Orientation, GC9A01A, Region};
+use gc9a01a_driver::geometry::{calculate_bounding_box, draw_polygon, get_coordinates};
 use panic_halt as _; // for using write! macro
 
 use rp2040_hal::timer::Timer;
@@ -27,14 +28,12 @@ use embedded_graphics::{
     mono_font::MonoTextStyleBuilder,
     pixelcolor::Rgb565,
     prelude::*,
-    primitives::{Circle, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle, Triangle},
+    primitives::{Circle, PrimitiveStyle, PrimitiveStyleBuilder, Rectangle},
     text::{Baseline, Text},
 };
 
 use profont::PROFONT_12_POINT;
 
-use libm::{cos, sin};
-
 const LCD_WIDTH: u32 = 240;
 const LCD_HEIGHT: u32 = 240;
 // Define static buffers
@@ -289,9 +288,9 @@ fn create_arrow(
     let style_red = PrimitiveStyleBuilder::new().fill_color(red).build();
     let style_red_9 = PrimitiveStyleBuilder::new().fill_color(red_9).build();
 
-    draw_polygon(framebuffer, &merged_points, style_red_9);
-    draw_polygon(framebuffer, &left_points[0..4], style_red);
-    draw_polygon(framebuffer, &right_points[0..4], style_red_9);
+    draw_polygon(framebuffer, &merged_points, style_red_9).unwrap();
+    draw_polygon(framebuffer, &left_points[0..4], style_red).unwrap();
+    draw_polygon(framebuffer, &right_points[0..4], style_red_9).unwrap();
 
     // Calculate the bounding box of the arrow
     let bounding_box = calculate_bounding_box(&merged_points, 10);
@@ -299,28 +298,6 @@ fn create_arrow(
     bounding_box
 }
 
-/// Draw a polygon on the frame buffer
-fn draw_polygon(framebuffer: &mut FrameBuffer, points: &[Point], style: PrimitiveStyle<Rgb565>) {
-    if points.len() < 3 {
-        return; // Not enough points to form a polygon
-    }
-
-    // Use fan triangulation from the first point
-    let first_point = points[0];
-    for i in 1..points.len() - 1 {
-        let triangle = Triangle::new(first_point, points[i], points[i + 1]).into_styled(style);
-        triangle.draw(framebuffer).unwrap();
-    }
-}
-
-// Helper function to calculate coordinates based on angle and radius
-fn get_coordinates(center: Point, radius: i32, angle: i32) -> Point {
-    let angle_rad = (angle as f32).to_radians() as f64;
-    let x = center.x + (radius as f32 * cos(angle_rad) as f32) as i32;
-    let y = center.y + (radius as f32 * sin(angle_rad) as f32) as i32;
-    Point::new(x, y)
-}
-
 /// Draws a circle on the frame buffer.
 fn draw_circle(framebuffer: &mut FrameBuffer, color: Rgb565, center: Point, radius: i32) {
     let style = PrimitiveStyleBuilder::new().fill_color(color).build();
@@ -344,34 +321,3 @@ fn create_button(framebuffer: &mut FrameBuffer, center_x: i32, center_y: i32) {
         circle_radius,
     );
 }
-
-/// Helper function to calculate the bounding box of a set of points with an optional padding.
-fn calculate_bounding_box(points: &[Point], padding: u16) -> Region {
-    let mut min_x = points[0].x as i32;
-    let mut max_x = points[0].x as i32;
-    let mut min_y = points[0].y as i32;
-    let mut max_y = points[0].y as i32;
-
-    for point in points.iter().skip(1) {
-        if point.x < min_x {
-            min_x = point.x;
-        }
-        if point.x > max_x {
-            max_x = point.x;
-        }
-        if point.y < min_y {
-            min_y = point.y;
-        }
-        if point.y > max_y {
-            max_y = point.y;
-        }
-    }
-
-    let padding = padding as i32;
-    Region {
-        x: (min_x - padding) as u16,
-        y: (min_y - padding) as u16,
-        width: (max_x - min_x + 2 * padding) as u32,
-        height: (max_y - min_y + 2 * padding) as u32,
-    }
-}
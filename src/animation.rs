@@ -0,0 +1,82 @@
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, Region, GC9A01A};
+
+/// Plays a looping sequence of RGB565 images, typically embedded with `include_bytes!`.
+///
+/// Every frame covers the same region of the display and is sent directly from flash
+/// with `GC9A01A::draw_image_sized`, so no frame buffer is needed.
+pub struct Animation<'a> {
+    /// Frame data, each `region.width * region.height * 2` bytes.
+    frames: &'a [&'static [u8]],
+
+    /// Where the frames are drawn.
+    region: Region,
+
+    /// Index of the frame drawn last, if any.
+    current: Option<usize>,
+}
+
+impl<'a> Animation<'a> {
+    /// Creates an animation.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - The frames, in playback order.
+    /// * `region` - Where the frames are drawn; every frame must match its size.
+    pub fn new(frames: &'a [&'static [u8]], region: Region) -> Self {
+        Self {
+            frames,
+            region,
+            current: None,
+        }
+    }
+
+    /// Draws the next frame, going back to the first one after the last.
+    ///
+    /// # Arguments
+    ///
+    /// * `display` - The display to draw on.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::BufferSize` if the
+    /// frame does not match the region's size. An animation without frames draws nothing.
+    pub fn next_frame<SPI, DC, CS, RST>(
+        &mut self,
+        display: &mut GC9A01A<SPI, DC, CS, RST>,
+    ) -> Result<(), Error>
+    where
+        SPI: SpiDevice,
+        DC: OutputPin,
+        CS: OutputPin,
+        RST: OutputPin,
+    {
+        if self.frames.is_empty() {
+            return Ok(());
+        }
+
+        let index = self.current.map_or(0, |i| (i + 1) % self.frames.len());
+        display.draw_image_sized(
+            self.frames[index],
+            self.region.x,
+            self.region.y,
+            self.region.width,
+            self.region.height,
+        )?;
+        self.current = Some(index);
+
+        Ok(())
+    }
+
+    /// Returns the index of the frame drawn last, or `None` before the first frame.
+    pub fn current_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Restarts the animation, so the next call to `next_frame` draws the first frame.
+    pub fn rewind(&mut self) {
+        self.current = None;
+    }
+}
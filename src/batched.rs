@@ -0,0 +1,160 @@
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, Instruction, GC9A01A, RAM_SIZE};
+
+/// Maximum number of pixels in a buffered row.
+const ROW_PIXELS: usize = RAM_SIZE as usize;
+
+/// A `DrawTarget` that draws directly to the panel one row at a time.
+///
+/// Pixels are collected in a single row buffer. When a pixel lands on a different row, the
+/// pixels drawn on the current row are sent, one address window per contiguous run. This
+/// is much faster than the per-pixel `DrawTarget` of `GC9A01A` for content drawn in row
+/// order, such as filled shapes, images and text, without needing a full frame buffer.
+///
+/// Pending pixels are sent when the target is dropped; call `flush` to see any error
+/// from that last transfer.
+pub struct GC9A01ABatched<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    /// Driver the rows are sent to.
+    driver: &'a mut GC9A01A<SPI, DC, CS, RST>,
+
+    /// Pixels of the current row, as big-endian RGB565.
+    row: [u8; ROW_PIXELS * 2],
+
+    /// Which pixels of the current row have been drawn.
+    dirty: [bool; ROW_PIXELS],
+
+    /// The row being collected, if any pixel has been drawn on it.
+    y: Option<u16>,
+}
+
+impl<'a, SPI, DC, CS, RST> GC9A01ABatched<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    /// Creates a batched draw target for a driver.
+    ///
+    /// # Arguments
+    ///
+    /// * `driver` - An initialized display driver.
+    pub fn new(driver: &'a mut GC9A01A<SPI, DC, CS, RST>) -> Self {
+        Self {
+            driver,
+            row: [0; ROW_PIXELS * 2],
+            dirty: [false; ROW_PIXELS],
+            y: None,
+        }
+    }
+
+    /// Sends the pixels drawn on the current row.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        let Some(y) = self.y.take() else {
+            return Ok(());
+        };
+
+        let mut x = 0;
+        while x < ROW_PIXELS {
+            if !self.dirty[x] {
+                x += 1;
+                continue;
+            }
+
+            let start = x;
+            while x < ROW_PIXELS && self.dirty[x] {
+                self.dirty[x] = false;
+                x += 1;
+            }
+
+            self.driver
+                .set_address_window(start as u16, y, x as u16 - 1, y)?;
+            self.driver.write_command(Instruction::RamWr as u8, &[])?;
+            self.driver.start_data()?;
+            self.driver.write_data(&self.row[start * 2..x * 2])?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, SPI, DC, CS, RST> DrawTarget for GC9A01ABatched<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    type Color = Rgb565;
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        self.driver.ensure_initialized()?;
+
+        let width = self.driver.width.min(ROW_PIXELS as u32) as i32;
+        let height = self.driver.height as i32;
+
+        for Pixel(coord, color) in pixels {
+            // Only draw pixels that would be on screen
+            if coord.x < 0 || coord.y < 0 || coord.x >= width || coord.y >= height {
+                continue;
+            }
+            let (x, y) = (coord.x as u16, coord.y as u16);
+            if self.driver.is_clipped(x, y) {
+                continue;
+            }
+
+            if self.y != Some(y) {
+                self.flush()?;
+                self.y = Some(y);
+            }
+
+            let index = x as usize;
+            self.row[index * 2..index * 2 + 2].copy_from_slice(&color.into_storage().to_be_bytes());
+            self.dirty[index] = true;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, SPI, DC, CS, RST> OriginDimensions for GC9A01ABatched<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    fn size(&self) -> Size {
+        self.driver.size()
+    }
+}
+
+impl<'a, SPI, DC, CS, RST> Drop for GC9A01ABatched<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    fn drop(&mut self) {
+        // Errors cannot be reported from drop; `flush` is there for callers who need them
+        let _ = self.flush();
+    }
+}
@@ -0,0 +1,77 @@
+/// Per-unit panel tuning values.
+///
+/// `GC9A01A::load_calibration` sends these to the following registers:
+///
+/// * `gamma_positive[0]` - SET_GAMMA1 (0xF0)
+/// * `gamma_positive[1]` - SET_GAMMA2 (0xF1)
+/// * `gamma_negative[0]` - SET_GAMMA3 (0xF2)
+/// * `gamma_negative[1]` - SET_GAMMA4 (0xF3)
+/// * `vreg1a` - Power Control 4 / Vreg1a voltage (0xC3)
+/// * `vreg1b` - Power Control 5 / Vreg1b voltage (0xC4)
+/// * `vreg2a` - Vreg2a voltage (0xC9)
+///
+/// The default holds the values written by `init`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Calibration {
+    pub gamma_positive: [[u8; 6]; 2],
+    pub gamma_negative: [[u8; 6]; 2],
+    pub vreg1a: u8,
+    pub vreg1b: u8,
+    pub vreg2a: u8,
+}
+
+impl Default for Calibration {
+    fn default() -> Self {
+        Self {
+            gamma_positive: [
+                [0x45, 0x09, 0x08, 0x08, 0x26, 0x2A],
+                [0x43, 0x70, 0x72, 0x36, 0x37, 0x6F],
+            ],
+            gamma_negative: [
+                [0x45, 0x09, 0x08, 0x08, 0x26, 0x2A],
+                [0x43, 0x70, 0x72, 0x36, 0x37, 0x6F],
+            ],
+            vreg1a: 0x13,
+            vreg1b: 0x13,
+            vreg2a: 0x22,
+        }
+    }
+}
+
+impl Calibration {
+    /// Size of the serialized form in bytes.
+    pub const SIZE: usize = 27;
+
+    /// Serializes the calibration, e.g. for storing it in flash.
+    ///
+    /// The layout is the four gamma tables in register order followed by `vreg1a`,
+    /// `vreg1b` and `vreg2a`.
+    pub fn to_bytes(&self) -> [u8; Self::SIZE] {
+        let mut bytes = [0u8; Self::SIZE];
+        bytes[0..6].copy_from_slice(&self.gamma_positive[0]);
+        bytes[6..12].copy_from_slice(&self.gamma_positive[1]);
+        bytes[12..18].copy_from_slice(&self.gamma_negative[0]);
+        bytes[18..24].copy_from_slice(&self.gamma_negative[1]);
+        bytes[24] = self.vreg1a;
+        bytes[25] = self.vreg1b;
+        bytes[26] = self.vreg2a;
+        bytes
+    }
+
+    /// Deserializes a calibration written by `to_bytes`.
+    pub fn from_bytes(bytes: &[u8; Self::SIZE]) -> Self {
+        let table = |start: usize| {
+            let mut table = [0u8; 6];
+            table.copy_from_slice(&bytes[start..start + 6]);
+            table
+        };
+
+        Self {
+            gamma_positive: [table(0), table(6)],
+            gamma_negative: [table(12), table(18)],
+            vreg1a: bytes[24],
+            vreg1b: bytes[25],
+            vreg2a: bytes[26],
+        }
+    }
+}
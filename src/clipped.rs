@@ -0,0 +1,83 @@
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*, primitives::Rectangle};
+
+use crate::Region;
+
+/// A draw target that only lets pixels inside a region through to another target.
+///
+/// This bounds direct-to-panel drawing to an area without a frame buffer, for example
+/// with `GC9A01A` or `GC9A01ABatched` as the inner target. Coordinates stay those of the
+/// inner target; the bounding box reported to embedded-graphics is the clip region.
+pub struct Clipped<'a, D>
+where
+    D: DrawTarget<Color = Rgb565>,
+{
+    /// The target pixels are forwarded to.
+    target: &'a mut D,
+
+    /// The area pixels are kept in.
+    bounds: Rectangle,
+}
+
+impl<'a, D> Clipped<'a, D>
+where
+    D: DrawTarget<Color = Rgb565>,
+{
+    /// Creates a clipped view of a draw target.
+    ///
+    /// # Arguments
+    ///
+    /// * `target` - The target to draw to.
+    /// * `region` - The area pixels are kept in. It is also clipped to the target.
+    pub fn new(target: &'a mut D, region: Region) -> Self {
+        let bounds = Rectangle::new(
+            Point::new(region.x as i32, region.y as i32),
+            Size::new(region.width, region.height),
+        )
+        .intersection(&target.bounding_box());
+
+        Self { target, bounds }
+    }
+}
+
+impl<'a, D> DrawTarget for Clipped<'a, D>
+where
+    D: DrawTarget<Color = Rgb565>,
+{
+    type Color = Rgb565;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let bounds = self.bounds;
+        self.target.draw_iter(
+            pixels
+                .into_iter()
+                .filter(|Pixel(point, _)| bounds.contains(*point)),
+        )
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        // Keep the inner target's fast path for solid fills
+        let area = area.intersection(&self.bounds);
+        if area.is_zero_sized() {
+            return Ok(());
+        }
+        self.target.fill_solid(&area, color)
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        let bounds = self.bounds;
+        self.fill_solid(&bounds, color)
+    }
+}
+
+impl<'a, D> Dimensions for Clipped<'a, D>
+where
+    D: DrawTarget<Color = Rgb565>,
+{
+    fn bounding_box(&self) -> Rectangle {
+        self.bounds
+    }
+}
@@ -0,0 +1,54 @@
+/// Blends two RGB565 colors channel by channel.
+///
+/// Returns `from` moved `num / den` of the way towards `to`.
+pub(crate) fn blend_rgb565(from: u16, to: u16, num: u32, den: u32) -> u16 {
+    let mix = |shift: u16, mask: u16| -> u16 {
+        let a = ((from >> shift) & mask) as i32;
+        let b = ((to >> shift) & mask) as i32;
+        let c = a + (b - a) * num as i32 / den as i32;
+        (c as u16 & mask) << shift
+    };
+
+    mix(11, 0x1F) | mix(5, 0x3F) | mix(0, 0x1F)
+}
+
+/// Packs an RGB565 color into the big-endian byte order the display expects.
+pub(crate) fn pack_rgb565_be(color: u16) -> [u8; 2] {
+    color.to_be_bytes()
+}
+
+/// Fills a buffer with one RGB565 color in big-endian byte order.
+///
+/// A trailing odd byte is left unchanged.
+pub(crate) fn fill_chunk_rgb565(buf: &mut [u8], color: u16) {
+    let bytes = pack_rgb565_be(color);
+    for pixel in buf.chunks_exact_mut(2) {
+        pixel.copy_from_slice(&bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pack_is_big_endian() {
+        assert_eq!(pack_rgb565_be(0xF800), [0xF8, 0x00]);
+        assert_eq!(pack_rgb565_be(0x07E0), [0x07, 0xE0]);
+        assert_eq!(pack_rgb565_be(0x001F), [0x00, 0x1F]);
+    }
+
+    #[test]
+    fn fill_repeats_the_packed_color() {
+        let mut buf = [0u8; 6];
+        fill_chunk_rgb565(&mut buf, 0x1234);
+        assert_eq!(buf, [0x12, 0x34, 0x12, 0x34, 0x12, 0x34]);
+    }
+
+    #[test]
+    fn fill_leaves_a_trailing_odd_byte_unchanged() {
+        let mut buf = [0xAAu8; 5];
+        fill_chunk_rgb565(&mut buf, 0x1234);
+        assert_eq!(buf, [0x12, 0x34, 0x12, 0x34, 0xAA]);
+    }
+}
@@ -0,0 +1,174 @@
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, FrameBuffer, Region, GC9A01A};
+
+/// High-level display that combines the driver, a frame buffer and dirty region tracking.
+///
+/// All drawing goes into the frame buffer. Every draw call records the bounding box of the
+/// pixels it touched as a region on the driver, and `present` pushes only those regions to
+/// the panel before clearing the list.
+pub struct Display<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    /// Underlying display driver.
+    driver: GC9A01A<SPI, DC, CS, RST>,
+
+    /// Frame buffer that all drawing is rendered into.
+    framebuffer: FrameBuffer<'a>,
+}
+
+impl<'a, SPI, DC, CS, RST> Display<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    /// Creates a new display from an initialized driver and a frame buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `driver` - The display driver.
+    /// * `framebuffer` - The frame buffer to draw into. It must cover the full display.
+    pub fn new(driver: GC9A01A<SPI, DC, CS, RST>, framebuffer: FrameBuffer<'a>) -> Self {
+        Self {
+            driver,
+            framebuffer,
+        }
+    }
+
+    /// Draws into the frame buffer.
+    ///
+    /// The closure receives the display as a `DrawTarget`; everything it draws is tracked
+    /// as dirty and sent to the panel on the next call to `present`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Closure performing the drawing.
+    ///
+    /// # Returns
+    ///
+    /// The value returned by the closure.
+    pub fn draw<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        f(self)
+    }
+
+    /// Sends all dirty regions to the panel and clears the region list.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn present(&mut self) -> Result<(), Error> {
+        self.driver
+            .show_regions_and_clear(self.framebuffer.get_buffer())
+    }
+
+    /// Returns a reference to the underlying driver.
+    pub fn driver(&self) -> &GC9A01A<SPI, DC, CS, RST> {
+        &self.driver
+    }
+
+    /// Returns a mutable reference to the underlying driver.
+    pub fn driver_mut(&mut self) -> &mut GC9A01A<SPI, DC, CS, RST> {
+        &mut self.driver
+    }
+
+    /// Returns a reference to the frame buffer.
+    pub fn framebuffer(&self) -> &FrameBuffer<'a> {
+        &self.framebuffer
+    }
+
+    /// Consumes the display and returns the driver and frame buffer.
+    pub fn release(self) -> (GC9A01A<SPI, DC, CS, RST>, FrameBuffer<'a>) {
+        (self.driver, self.framebuffer)
+    }
+
+    /// Records a region as dirty.
+    ///
+    /// If the driver's region list is full, it is replaced by a single region covering
+    /// the whole display so nothing drawn is lost.
+    fn mark_dirty(&mut self, region: Region) {
+        if self.driver.store_region(region).is_err() {
+            let size = self.framebuffer.size();
+            self.driver.clear_regions();
+            // Cannot fail: the list has just been emptied.
+            let _ = self.driver.store_region(Region {
+                x: 0,
+                y: 0,
+                width: size.width,
+                height: size.height,
+            });
+        }
+    }
+}
+
+impl<'a, SPI, DC, CS, RST> DrawTarget for Display<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    type Color = Rgb565;
+    type Error = ();
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let size = self.framebuffer.size();
+        let mut bounds: Option<(i32, i32, i32, i32)> = None;
+
+        let tracked = pixels.into_iter().inspect(|Pixel(coord, _)| {
+            // Only pixels that land in the frame buffer make a region dirty
+            if coord.x >= 0
+                && coord.y >= 0
+                && coord.x < size.width as i32
+                && coord.y < size.height as i32
+            {
+                bounds = Some(match bounds {
+                    Some((min_x, min_y, max_x, max_y)) => (
+                        min_x.min(coord.x),
+                        min_y.min(coord.y),
+                        max_x.max(coord.x),
+                        max_y.max(coord.y),
+                    ),
+                    None => (coord.x, coord.y, coord.x, coord.y),
+                });
+            }
+        });
+        self.framebuffer.draw_iter(tracked)?;
+
+        if let Some((min_x, min_y, max_x, max_y)) = bounds {
+            self.mark_dirty(Region {
+                x: min_x as u16,
+                y: min_y as u16,
+                width: (max_x - min_x + 1) as u32,
+                height: (max_y - min_y + 1) as u32,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, SPI, DC, CS, RST> OriginDimensions for Display<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    fn size(&self) -> Size {
+        self.framebuffer.size()
+    }
+}
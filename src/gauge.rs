@@ -0,0 +1,181 @@
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+
+use crate::geometry::{calculate_bounding_box, get_coordinates};
+use crate::{FrameBuffer, Region};
+
+/// A needle gauge drawn into a `FrameBuffer`.
+///
+/// Angles are in degrees, measured clockwise from the 3 o'clock position like
+/// `geometry::get_coordinates`. The scale runs from `start_deg` to `end_deg`, which may be
+/// given in either order; `end_deg` may exceed 360 for a scale that passes 3 o'clock.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Gauge {
+    /// Center of the dial.
+    center: Point,
+
+    /// Distance from the center to the needle tip and the outer end of the ticks.
+    radius: u32,
+
+    /// Angle of the start of the scale.
+    start_deg: i32,
+
+    /// Angle of the end of the scale.
+    end_deg: i32,
+}
+
+impl Gauge {
+    /// Creates a gauge.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - Center of the dial.
+    /// * `radius` - Length of the needle and outer radius of the ticks.
+    /// * `start_deg` - Angle of the start of the scale.
+    /// * `end_deg` - Angle of the end of the scale.
+    pub fn new(center: Point, radius: u32, start_deg: i32, end_deg: i32) -> Self {
+        Self {
+            center,
+            radius,
+            start_deg,
+            end_deg,
+        }
+    }
+
+    /// Draws the needle pointing at an angle on the scale.
+    ///
+    /// The needle is a narrow pointer from the center to the radius with a short tail on
+    /// the opposite side. Restore the returned region from the background before drawing
+    /// the needle at its next position.
+    ///
+    /// # Arguments
+    ///
+    /// * `fb` - The frame buffer to draw into.
+    /// * `value_deg` - Angle the needle points at, clamped to the scale.
+    /// * `color` - Color of the needle.
+    ///
+    /// # Returns
+    ///
+    /// The `Region` covered by the needle, with two pixels of padding, clipped to the
+    /// frame buffer.
+    pub fn draw_needle(&self, fb: &mut FrameBuffer, value_deg: i32, color: Rgb565) -> Region {
+        let angle = value_deg.clamp(
+            self.start_deg.min(self.end_deg),
+            self.start_deg.max(self.end_deg),
+        );
+        let radius = self.radius as i32;
+        let shoulder = radius - radius / 40 - 1;
+        let tail = radius / 4;
+
+        let points = [
+            get_coordinates(self.center, radius, angle),
+            get_coordinates(self.center, shoulder, angle + 2),
+            get_coordinates(self.center, tail, angle + 170),
+            get_coordinates(self.center, tail, angle + 190),
+            get_coordinates(self.center, shoulder, angle - 2),
+        ];
+        fb.fill_polygon(&points, color);
+
+        let mut region = calculate_bounding_box(&points, 2);
+        let size = fb.size();
+        region.width = region.width.min(size.width.saturating_sub(region.x as u32));
+        region.height = region
+            .height
+            .min(size.height.saturating_sub(region.y as u32));
+        region
+    }
+
+    /// Draws evenly spaced tick marks along the scale.
+    ///
+    /// The ticks run inward from the radius for a tenth of its length, with the first and
+    /// last on the ends of the scale.
+    ///
+    /// # Arguments
+    ///
+    /// * `fb` - The frame buffer to draw into.
+    /// * `count` - Number of ticks. A single tick is drawn at the start of the scale.
+    /// * `color` - Color of the ticks.
+    pub fn draw_ticks(&self, fb: &mut FrameBuffer, count: u32, color: Rgb565) {
+        let radius = self.radius as i32;
+        let inner = radius - (radius / 10).max(1);
+        let span = self.end_deg - self.start_deg;
+
+        for i in 0..count as i32 {
+            let angle = if count > 1 {
+                self.start_deg + span * i / (count as i32 - 1)
+            } else {
+                self.start_deg
+            };
+            fb.draw_line_aa(
+                get_coordinates(self.center, inner, angle),
+                get_coordinates(self.center, radius, angle),
+                color,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: usize = 100 * 100 * 2;
+
+    #[test]
+    fn needle_is_clamped_to_the_scale() {
+        let gauge = Gauge::new(Point::new(50, 50), 40, 135, 405);
+        for (outside, end) in [(500, 405), (0, 135)] {
+            let mut clamped = [0u8; SIZE];
+            let mut at_end = [0u8; SIZE];
+            let a = gauge.draw_needle(
+                &mut FrameBuffer::new(&mut clamped, 100, 100),
+                outside,
+                Rgb565::RED,
+            );
+            let b = gauge.draw_needle(
+                &mut FrameBuffer::new(&mut at_end, 100, 100),
+                end,
+                Rgb565::RED,
+            );
+            assert!(a == b);
+            assert!(clamped == at_end);
+        }
+    }
+
+    #[test]
+    fn needle_region_is_clipped_to_the_buffer() {
+        // The needle points right, past the edge of the buffer
+        let gauge = Gauge::new(Point::new(90, 90), 40, 0, 90);
+        let mut buffer = [0u8; SIZE];
+        let region =
+            gauge.draw_needle(&mut FrameBuffer::new(&mut buffer, 100, 100), 0, Rgb565::RED);
+        assert!(region.width > 0 && region.height > 0);
+        assert!(region.x as u32 + region.width <= 100);
+        assert!(region.y as u32 + region.height <= 100);
+    }
+
+    #[test]
+    fn zero_ticks_draw_nothing() {
+        let gauge = Gauge::new(Point::new(50, 50), 40, 135, 405);
+        let mut buffer = [0u8; SIZE];
+        gauge.draw_ticks(
+            &mut FrameBuffer::new(&mut buffer, 100, 100),
+            0,
+            Rgb565::WHITE,
+        );
+        assert!(buffer.iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn single_tick_is_drawn_at_the_start() {
+        let gauge = Gauge::new(Point::new(50, 50), 40, 135, 405);
+        let mut one = [0u8; SIZE];
+        let mut two = [0u8; SIZE];
+        gauge.draw_ticks(&mut FrameBuffer::new(&mut one, 100, 100), 1, Rgb565::WHITE);
+        gauge.draw_ticks(&mut FrameBuffer::new(&mut two, 100, 100), 2, Rgb565::WHITE);
+
+        // The single tick is the first of the two, and the second adds more pixels
+        assert!(one.iter().any(|&b| b != 0));
+        assert!(one.iter().zip(two.iter()).all(|(&a, &b)| a == 0 || a == b));
+        assert!(one != two);
+    }
+}
@@ -0,0 +1,136 @@
+//! Geometry helpers for circular user interfaces, such as gauge needles and compass arrows.
+
+use embedded_graphics::{
+    mono_font::{MonoFont, MonoTextStyle},
+    pixelcolor::Rgb565,
+    prelude::*,
+    primitives::{PrimitiveStyle, Triangle},
+    text::{Baseline, Text},
+};
+
+use crate::Region;
+
+/// Returns the point at a given angle and distance from a center.
+///
+/// Angles are in degrees, measured clockwise from the 3 o'clock position since the y-axis
+/// points down.
+///
+/// # Arguments
+///
+/// * `center` - The center point.
+/// * `radius` - Distance from the center.
+/// * `angle` - Angle in degrees.
+///
+/// # Returns
+///
+/// The point on the circle.
+pub fn get_coordinates(center: Point, radius: i32, angle: i32) -> Point {
+    let angle_rad = (angle as f32).to_radians();
+    let x = center.x + (radius as f32 * libm::cosf(angle_rad)) as i32;
+    let y = center.y + (radius as f32 * libm::sinf(angle_rad)) as i32;
+    Point::new(x, y)
+}
+
+/// Calculates the bounding box of a set of points, with padding on every side.
+///
+/// The box is clamped at zero so points or padding left of or above the screen do not
+/// wrap around when converted to `Region` coordinates.
+///
+/// # Arguments
+///
+/// * `points` - The points to enclose.
+/// * `padding` - Number of pixels to add on every side.
+///
+/// # Returns
+///
+/// The enclosing `Region`, or an empty region if there are no points or all of them lie
+/// left of or above the screen.
+pub fn calculate_bounding_box(points: &[Point], padding: u16) -> Region {
+    let Some(first) = points.first() else {
+        return Region::default();
+    };
+
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (first.x, first.y, first.x, first.y);
+    for point in points.iter().skip(1) {
+        min_x = min_x.min(point.x);
+        min_y = min_y.min(point.y);
+        max_x = max_x.max(point.x);
+        max_y = max_y.max(point.y);
+    }
+
+    let padding = padding as i32;
+    let min_x = (min_x - padding).max(0);
+    let min_y = (min_y - padding).max(0);
+    let max_x = max_x + padding;
+    let max_y = max_y + padding;
+    if max_x < min_x || max_y < min_y {
+        return Region::default();
+    }
+
+    Region {
+        x: min_x.min(u16::MAX as i32) as u16,
+        y: min_y.min(u16::MAX as i32) as u16,
+        width: (max_x - min_x + 1) as u32,
+        height: (max_y - min_y + 1) as u32,
+    }
+}
+
+/// Calculates the region covered by text drawn in a monospaced font.
+///
+/// The size comes from the font's character size and spacing, and the vertical position
+/// from the baseline the text is drawn with, so the region fits the text exactly however
+/// many characters it has. Text containing line breaks covers several lines.
+///
+/// # Arguments
+///
+/// * `text` - The text to measure.
+/// * `font` - The font the text is drawn with.
+/// * `position` - The position passed to `Text::with_baseline`.
+/// * `baseline` - The baseline passed to `Text::with_baseline`.
+///
+/// # Returns
+///
+/// The `Region` covered by the text, clamped at zero like `calculate_bounding_box`, or an
+/// empty region for empty text.
+pub fn text_region(text: &str, font: &MonoFont<'_>, position: Point, baseline: Baseline) -> Region {
+    let style = MonoTextStyle::new(font, Rgb565::BLACK);
+    let bounds = Text::with_baseline(text, position, style, baseline).bounding_box();
+    let Some(bottom_right) = bounds.bottom_right() else {
+        return Region::default();
+    };
+
+    calculate_bounding_box(&[bounds.top_left, bottom_right], 0)
+}
+
+/// Draws a convex polygon as a fan of triangles from its first point.
+///
+/// # Arguments
+///
+/// * `target` - The target to draw on, such as a `FrameBuffer`.
+/// * `points` - The polygon's corners, in order. Fewer than three points draw nothing.
+/// * `style` - The style of the triangles.
+///
+/// # Returns
+///
+/// `Result<(), D::Error>` indicating success or failure.
+pub fn draw_polygon<D>(
+    target: &mut D,
+    points: &[Point],
+    style: PrimitiveStyle<Rgb565>,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget<Color = Rgb565>,
+{
+    if points.len() < 3 {
+        return Ok(());
+    }
+
+    let first_point = points[0];
+    for pair in points[1..].windows(2) {
+        Triangle::new(first_point, pair[0], pair[1])
+            .into_styled(style)
+            .draw(target)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,130 @@
+use embedded_graphics::{
+    mono_font::{MonoFont, MonoTextStyleBuilder},
+    pixelcolor::Rgb565,
+    prelude::*,
+    text::{Baseline, Text},
+};
+
+use crate::{Error, FrameBuffer};
+
+/// Pre-rendered RGB565 tiles for a fixed set of characters in one font.
+///
+/// Rendering text through embedded-graphics walks the font bitmap pixel by pixel on every
+/// frame. A cache renders each character once, with its background, and
+/// `FrameBuffer::draw_cached_text` then copies whole tile rows instead.
+///
+/// Each glyph takes `character width * character height * 2` bytes of the storage passed
+/// to `new`; the ten digits of a 10x20 font need 4000 bytes. At most `MAX_GLYPHS`
+/// characters can be cached.
+pub struct GlyphCache<'a> {
+    /// Rendered tiles, one after another in the order of `chars`.
+    tiles: &'a mut [u8],
+
+    /// Cached characters.
+    chars: [char; GlyphCache::MAX_GLYPHS],
+
+    /// Number of cached characters.
+    len: usize,
+
+    /// Size of each tile.
+    glyph_width: u32,
+    glyph_height: u32,
+
+    /// Horizontal distance from one character to the next.
+    advance: u32,
+}
+
+impl<'a> GlyphCache<'a> {
+    /// Maximum number of characters in a cache.
+    pub const MAX_GLYPHS: usize = 64;
+
+    /// Renders a set of characters into tiles.
+    ///
+    /// # Arguments
+    ///
+    /// * `tiles` - Storage for the tiles, at least `chars.len()` glyphs of the font.
+    /// * `font` - The font to render with.
+    /// * `chars` - The characters to cache. Repeated characters are cached once.
+    /// * `text_color` - Color of the characters.
+    /// * `background` - Color of the rest of each tile.
+    ///
+    /// # Returns
+    ///
+    /// The cache, or `Error::BufferSize` if there are more than `MAX_GLYPHS` characters or
+    /// the storage is too small.
+    pub fn new(
+        tiles: &'a mut [u8],
+        font: &MonoFont<'_>,
+        chars: &str,
+        text_color: Rgb565,
+        background: Rgb565,
+    ) -> Result<Self, Error> {
+        let glyph_width = font.character_size.width;
+        let glyph_height = font.character_size.height;
+        let tile_size = (glyph_width * glyph_height * 2) as usize;
+
+        let mut cache = Self {
+            tiles,
+            chars: ['\0'; Self::MAX_GLYPHS],
+            len: 0,
+            glyph_width,
+            glyph_height,
+            advance: glyph_width + font.character_spacing,
+        };
+
+        let style = MonoTextStyleBuilder::new()
+            .font(font)
+            .text_color(text_color)
+            .background_color(background)
+            .build();
+        let mut utf8 = [0u8; 4];
+
+        for c in chars.chars() {
+            if cache.chars[..cache.len].contains(&c) {
+                continue;
+            }
+            if cache.len == Self::MAX_GLYPHS {
+                return Err(Error::BufferSize);
+            }
+
+            let start = cache.len * tile_size;
+            let tile = cache
+                .tiles
+                .get_mut(start..start + tile_size)
+                .ok_or(Error::BufferSize)?;
+            let mut target = FrameBuffer::new(tile, glyph_width, glyph_height);
+            // Drawing into a frame buffer cannot fail
+            let _ = Text::with_baseline(
+                c.encode_utf8(&mut utf8),
+                Point::zero(),
+                style,
+                Baseline::Top,
+            )
+            .draw(&mut target);
+
+            cache.chars[cache.len] = c;
+            cache.len += 1;
+        }
+
+        Ok(cache)
+    }
+
+    /// Returns the size of each tile.
+    pub fn glyph_size(&self) -> Size {
+        Size::new(self.glyph_width, self.glyph_height)
+    }
+
+    /// Returns the horizontal distance from one character to the next.
+    pub fn advance(&self) -> u32 {
+        self.advance
+    }
+
+    /// Returns the tile for a character, if it is cached.
+    pub(crate) fn tile(&self, c: char) -> Option<&[u8]> {
+        let index = self.chars[..self.len]
+            .iter()
+            .position(|&cached| cached == c)?;
+        let tile_size = (self.glyph_width * self.glyph_height * 2) as usize;
+        Some(&self.tiles[index * tile_size..(index + 1) * tile_size])
+    }
+}
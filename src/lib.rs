@@ -1,51 +1,133 @@
 #![no_std]
-#![no_main]
 
+#[cfg(feature = "record")]
+extern crate alloc;
+
+use core::task::Poll;
+use embedded_graphics::image::ImageRaw;
+use embedded_graphics::mono_font::{MonoFont, MonoTextStyleBuilder};
+use embedded_graphics::pixelcolor::raw::RawU16;
+use embedded_graphics::text::{Baseline, Text};
 use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
 use embedded_hal::delay::DelayNs;
-use embedded_hal::spi::SpiDevice;
 use embedded_hal::digital::OutputPin;
-use embedded_graphics::pixelcolor::raw::RawU16;
+use embedded_hal::spi::SpiDevice;
+
+mod animation;
+mod batched;
+mod calibration;
+mod clipped;
+mod color;
+mod display;
+mod gauge;
+pub mod geometry;
+mod glyph_cache;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod multi;
+mod pacer;
+mod palette;
+mod pixel_writer;
+#[cfg(feature = "qoi")]
+mod qoi;
+mod reader;
+mod round_display;
+
+pub use animation::Animation;
+pub use batched::GC9A01ABatched;
+pub use calibration::Calibration;
+pub use clipped::Clipped;
+pub use display::Display;
+pub use gauge::Gauge;
+pub use glyph_cache::GlyphCache;
+#[cfg(feature = "metrics")]
+pub use metrics::TimingStats;
+pub use multi::{ChipSelects, MultiGC9A01A};
+pub use pacer::AdaptivePacer;
+pub use palette::PaletteFrameBuffer;
+pub use pixel_writer::PixelWriter;
+pub use reader::FrameReader;
+pub use round_display::RoundDisplay;
 
 /// Enumeration of instructions for the GC9A01A display.
 pub enum Instruction {
-    Nop = 0x00,     // No Operation
-    SwReset = 0x01, // Software Reset
-    RddId = 0x04,   // Read Display Identification Information
-    RddSt = 0x09,   // Read Display Status
-    SlpIn = 0x10,   // Enter Sleep Mode
-    SlpOut = 0x11,  // Sleep Out Mode
-    PtlOn = 0x12,   // Partial Mode ON
-    NorOn = 0x13,   // Normal Display Mode ON
-    InvOff = 0x20,  // Display Inversion OFF
-    InvOn = 0x21,   // Display Inversion ON
-    DispOff = 0x28, // Display OFF
-    DispOn = 0x29,  // Display ON
-    CaSet = 0x2A,   // Column Address Set
-    RaSet = 0x2B,   // Row Address Set
-    RamWr = 0x2C,   // Memory Write
-    RamRd = 0x2E,   // Memory Read
-    PtlAr = 0x30,   // Partial Area
-    ColMod = 0x3A,  // Pixel Format Set
-    MadCtl = 0x36,  // Memory Access Control
-    FrmCtr1 = 0xB1, // Frame Rate Control (In normal mode/Full colors)
-    FrmCtr2 = 0xB2, // Frame Rate Control (In idle mode/8 colors)
-    FrmCtr3 = 0xB3, // Frame Rate Control (In partial mode/full colors)
-    InvCtr = 0xB4,  // Display Inversion Control
-    DisSet5 = 0xB6, // Display Function Control
-    PwCtr1 = 0xC0,  // Power Control 1
-    PwCtr2 = 0xC1,  // Power Control 2
-    PwCtr3 = 0xC2,  // Power Control 3
-    PwCtr4 = 0xC3,  // Power Control 4
-    PwCtr5 = 0xC4,  // Power Control 5
-    VmCtr1 = 0xC5,  // VCOM Control 1
-    RdId1 = 0xDA,   // Read ID1
-    RdId2 = 0xDB,   // Read ID2
-    RdId3 = 0xDC,   // Read ID3
-    RdId4 = 0xDD,   // Read ID4
-    PwCtr6 = 0xFC,  // Power Control 6
-    GmcTrp1 = 0xE0, // Positive Gamma Correction
-    GmcTrn1 = 0xE1, // Negative Gamma Correction
+    Nop = 0x00,       // No Operation
+    SwReset = 0x01,   // Software Reset
+    RddId = 0x04,     // Read Display Identification Information
+    RddSt = 0x09,     // Read Display Status
+    SlpIn = 0x10,     // Enter Sleep Mode
+    SlpOut = 0x11,    // Sleep Out Mode
+    PtlOn = 0x12,     // Partial Mode ON
+    NorOn = 0x13,     // Normal Display Mode ON
+    InvOff = 0x20,    // Display Inversion OFF
+    InvOn = 0x21,     // Display Inversion ON
+    GamSet = 0x26,    // Gamma Set
+    DispOff = 0x28,   // Display OFF
+    DispOn = 0x29,    // Display ON
+    CaSet = 0x2A,     // Column Address Set
+    RaSet = 0x2B,     // Row Address Set
+    RamWr = 0x2C,     // Memory Write
+    RamRd = 0x2E,     // Memory Read
+    PtlAr = 0x30,     // Partial Area
+    ColMod = 0x3A,    // Pixel Format Set
+    RamWrCont = 0x3C, // Write Memory Continue
+    MadCtl = 0x36,    // Memory Access Control
+    FrmCtr1 = 0xB1,   // Frame Rate Control (In normal mode/Full colors)
+    FrmCtr2 = 0xB2,   // Frame Rate Control (In idle mode/8 colors)
+    FrmCtr3 = 0xB3,   // Frame Rate Control (In partial mode/full colors)
+    InvCtr = 0xB4,    // Display Inversion Control
+    DisSet5 = 0xB6,   // Display Function Control
+    PwCtr1 = 0xC0,    // Power Control 1
+    PwCtr2 = 0xC1,    // Power Control 2
+    PwCtr3 = 0xC2,    // Power Control 3
+    PwCtr4 = 0xC3,    // Power Control 4
+    PwCtr5 = 0xC4,    // Power Control 5
+    VmCtr1 = 0xC5,    // VCOM Control 1
+    RdId1 = 0xDA,     // Read ID1
+    RdId2 = 0xDB,     // Read ID2
+    RdId3 = 0xDC,     // Read ID3
+    RdId4 = 0xDD,     // Read ID4
+    PwCtr6 = 0xFC,    // Power Control 6
+    GmcTrp1 = 0xE0,   // Positive Gamma Correction
+    GmcTrn1 = 0xE1,   // Negative Gamma Correction
+    Gamma1 = 0xF0,    // Set Gamma 1
+    Gamma2 = 0xF1,    // Set Gamma 2
+    Gamma3 = 0xF2,    // Set Gamma 3
+    Gamma4 = 0xF3,    // Set Gamma 4
+    Vreg2a = 0xC9,    // Vreg2a Voltage Control
+}
+
+/// Errors returned by the display driver.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// An SPI transfer failed. The operation is aborted with chip select released, so a
+    /// partially sent frame is never reported as success.
+    Spi,
+    /// Setting a GPIO pin failed.
+    Pin,
+    /// A drawing method was called before `init`.
+    NotInitialized,
+    /// The region list is full.
+    RegionsFull,
+    /// The selected display index is out of range.
+    InvalidSelection,
+    /// Reading frame data from a `FrameReader` failed.
+    Read,
+    /// A buffer is smaller than the area it is supposed to cover.
+    BufferSize,
+    /// Coordinates or a region fall outside the display or buffer, or overflow.
+    OutOfBounds,
+    /// A parameter is not one of the values the controller accepts.
+    InvalidArgument,
+}
+
+/// Why a stored region cannot be shown, as reported by `GC9A01A::validate_regions`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegionError {
+    /// The region extends past the right or bottom edge of the display.
+    OutOfBounds,
+    /// The region's far edge does not fit in `u16` coordinates.
+    Overflow,
 }
 
 /// Structure to represent a region.
@@ -57,6 +139,103 @@ pub struct Region {
     pub height: u32,
 }
 
+impl Region {
+    /// Returns true if the region covers no pixels.
+    pub fn is_empty(&self) -> bool {
+        self.width == 0 || self.height == 0
+    }
+
+    /// Returns true if a point lies inside the region.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate.
+    /// * `y` - Y-coordinate.
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        let (x, y) = (x as u32, y as u32);
+        x >= self.x as u32
+            && y >= self.y as u32
+            && x < self.x as u32 + self.width
+            && y < self.y as u32 + self.height
+    }
+}
+
+/// A pixel coordinate checked against a display's size.
+///
+/// Constructing a `ScreenPoint` is the only place coordinates are validated, so negative
+/// or out-of-range values are rejected up front instead of wrapping when cast to `u16`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ScreenPoint {
+    x: u16,
+    y: u16,
+}
+
+impl ScreenPoint {
+    /// Creates a point if it lies inside the given display.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate.
+    /// * `y` - Y-coordinate.
+    /// * `display` - The display or frame buffer whose size bounds the point.
+    ///
+    /// # Returns
+    ///
+    /// The point, or `None` if it lies outside the display.
+    pub fn new<D>(x: i32, y: i32, display: &D) -> Option<Self>
+    where
+        D: OriginDimensions,
+    {
+        let size = display.size();
+        if x < 0 || y < 0 || x as u32 >= size.width || y as u32 >= size.height {
+            return None;
+        }
+
+        Some(Self {
+            x: x as u16,
+            y: y as u16,
+        })
+    }
+
+    /// Returns the x-coordinate.
+    pub fn x(&self) -> u16 {
+        self.x
+    }
+
+    /// Returns the y-coordinate.
+    pub fn y(&self) -> u16 {
+        self.y
+    }
+}
+
+/// Saved display configuration, captured with `GC9A01A::state_snapshot`.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct DisplayState {
+    dx: u16,
+    dy: u16,
+    madctl: u8,
+    inverted: bool,
+}
+
+/// State of a non-blocking full-screen update started with `GC9A01A::begin_show`.
+pub struct ShowInProgress<'b> {
+    /// Buffer being sent to the display.
+    buffer: &'b [u8],
+
+    /// Number of bytes already sent.
+    sent: usize,
+}
+
+impl ShowInProgress<'_> {
+    /// Number of bytes sent per call to `GC9A01A::poll_show`.
+    const CHUNK_SIZE: usize = 1024;
+
+    /// Returns whether the whole buffer has been sent.
+    pub fn is_done(&self) -> bool {
+        self.sent >= self.buffer.len()
+    }
+}
+
 /// Driver for the GC9A01A display.
 pub struct GC9A01A<SPI, DC, CS, RST>
 where
@@ -86,10 +265,153 @@ where
     width: u32,
     height: u32,
     regions: [Option<Region>; 10],
+
+    /// Reset pulse timing in milliseconds: before, during and after the low pulse.
+    reset_pre_ms: u32,
+    reset_low_ms: u32,
+    reset_post_ms: u32,
+
+    /// Whether `init` has completed since the last reset.
+    initialized: bool,
+
+    /// Settle time in milliseconds skipped by the last init, waited out by `wait_ready`.
+    settle_ms: u32,
+
+    /// Start time and settle time of a pending sleep or wake transition, in microseconds.
+    settling: Option<(u32, u32)>,
+
+    /// MADCTL value last sent, by an init or by `set_orientation` and the other scan setters.
+    madctl: u8,
+
+    /// ML and MH bits chosen with `set_scan_direction`, kept when the orientation changes.
+    scan_direction: u8,
+
+    /// Whether display inversion is on.
+    inverted: bool,
+
+    /// Whether the init sequences turn display inversion on.
+    init_inversion: bool,
+
+    /// Whether `store_region` records regions.
+    region_tracking: bool,
+
+    /// Whether repeated writes to the same window skip the window and RamWr commands.
+    ram_write_continuation: bool,
+
+    /// Raw window last sent with `set_raw_window`, if no other window has been set since.
+    window: Option<[u16; 4]>,
+
+    /// Whether RamWr or RamWrCont was the last command sent.
+    ram_write_open: bool,
+
+    /// Callback that changes the SPI clock around reads.
+    clock_adjuster: Option<fn(u32)>,
+
+    /// Whether streamed writes send RamWrCont before each chunk after the first.
+    write_continue: bool,
+
+    /// Brightness applied to pixels sent by `show` and `show_region`, 255 for unchanged.
+    software_brightness: u8,
+
+    /// Maximum number of bytes per SPI write when streaming caller-supplied buffers.
+    chunk_size: usize,
+
+    /// Visible `(x_min, x_max)` span of each row when circular clipping is enabled.
+    circle_spans: Option<[(u16, u16); RAM_SIZE as usize]>,
+
+    /// Copy of the content sent to the panel, when mirroring is enabled.
+    mirror: Option<&'static mut [u8]>,
+
+    /// Window set by `set_address_window` and the byte position within it that the next
+    /// pixel data lands on, for updating the mirror.
+    mirror_cursor: Option<(Region, usize)>,
+
+    /// Monotonic microsecond clock used to time transfers.
+    #[cfg(feature = "metrics")]
+    clock: Option<fn() -> u32>,
+
+    /// Collected transfer durations.
+    #[cfg(feature = "metrics")]
+    timing_stats: TimingStats,
+
+    /// Duration of the last init, including its delays.
+    #[cfg(feature = "metrics")]
+    init_duration_us: Option<u32>,
+
+    /// Bytes captured instead of being sent while recording is enabled.
+    #[cfg(feature = "record")]
+    recorded: Option<alloc::vec::Vec<u8>>,
+}
+
+/// Size of the controller's frame memory in pixels along each axis.
+const RAM_SIZE: u16 = 240;
+
+/// Bytes per pixel in the RGB565 color format set by `init`.
+const BYTES_PER_PIXEL: usize = 2;
+
+/// Time the panel needs after SLPIN before it accepts the next command, in microseconds.
+const SLEEP_IN_SETTLE_US: u32 = 5_000;
+/// Time the panel needs after SLPOUT before it is ready to draw, in microseconds.
+const SLEEP_OUT_SETTLE_US: u32 = 120_000;
+
+/// Share of changed pixels, in percent, above which `show_diff` sends the whole frame.
+const DIFF_FULL_SHOW_PERCENT: usize = 50;
+/// Longest run of unchanged pixels `show_diff` sends along with the changes around it,
+/// since that is cheaper than setting up another address window.
+const DIFF_MERGE_GAP: usize = 8;
+
+/// Time `init` waits after turning the display on, in milliseconds.
+const DEFAULT_STABILIZE_MS: u32 = 200;
+
+/// Default number of bytes per SPI write when streaming buffers.
+const DEFAULT_CHUNK_SIZE: usize = 32;
+/// Number of bytes per SPI write inside `with_bulk_transfer`.
+const BULK_CHUNK_SIZE: usize = 4096;
+
+/// MADCTL row address order (mirror Y).
+const MADCTL_MY: u8 = 0x80;
+/// MADCTL column address order (mirror X).
+const MADCTL_MX: u8 = 0x40;
+/// MADCTL row/column exchange.
+const MADCTL_MV: u8 = 0x20;
+/// MADCTL vertical refresh order (ML).
+const MADCTL_ML: u8 = 0x10;
+/// MADCTL BGR color order.
+const MADCTL_BGR: u8 = 0x08;
+/// MADCTL horizontal refresh order (MH).
+const MADCTL_MH: u8 = 0x04;
+
+/// Builds a MADCTL value from explicit scan flags.
+///
+/// # Arguments
+///
+/// * `mirror_x` - Reverse the column address order (MX).
+/// * `mirror_y` - Reverse the row address order (MY).
+/// * `swap_xy` - Exchange rows and columns (MV).
+/// * `bgr` - Use BGR color order.
+///
+/// # Returns
+///
+/// The MADCTL register value.
+pub fn madctl_from(mirror_x: bool, mirror_y: bool, swap_xy: bool, bgr: bool) -> u8 {
+    let mut madctl = 0;
+    if mirror_x {
+        madctl |= MADCTL_MX;
+    }
+    if mirror_y {
+        madctl |= MADCTL_MY;
+    }
+    if swap_xy {
+        madctl |= MADCTL_MV;
+    }
+    if bgr {
+        madctl |= MADCTL_BGR;
+    }
+    madctl
 }
 
 /// Display orientation.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum Orientation {
     Portrait = 0x00,
     Landscape = 0x60,
@@ -97,6 +419,39 @@ pub enum Orientation {
     LandscapeSwapped = 0xA0,
 }
 
+/// Clockwise rotation applied to frame buffer contents.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Rotation {
+    Deg0,
+    Deg90,
+    Deg180,
+    Deg270,
+}
+
+/// Edge of the display a transition starts from.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// Test patterns for display bring-up.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum TestPattern {
+    /// Eight vertical color bars: white, yellow, cyan, green, magenta, red, blue, black.
+    ColorBars,
+    /// White grid lines every 20 pixels on black.
+    Crosshatch,
+    /// Red increasing left to right, green increasing top to bottom, blue decreasing
+    /// left to right.
+    Gradient,
+    /// A distinct color in each quadrant: red top-left, green top-right, blue bottom-left,
+    /// white bottom-right.
+    CornerMarkers,
+}
+
 impl<SPI, DC, CS, RST> GC9A01A<SPI, DC, CS, RST>
 where
     SPI: SpiDevice,
@@ -104,6 +459,12 @@ where
     CS: OutputPin,
     RST: OutputPin,
 {
+    /// Fastest SPI clock for writes, from the 16ns serial write cycle in the datasheet.
+    pub const MAX_WRITE_HZ: u32 = 62_500_000;
+
+    /// Fastest SPI clock for reads, from the 150ns serial read cycle in the datasheet.
+    pub const MAX_READ_HZ: u32 = 6_666_666;
+
     /// Creates a new driver instance that uses hardware SPI.
     ///
     /// # Arguments
@@ -127,6 +488,35 @@ where
             width,
             height,
             regions: [None; 10],
+            reset_pre_ms: 10,
+            reset_low_ms: 10,
+            reset_post_ms: 10,
+            initialized: false,
+            settle_ms: 0,
+            settling: None,
+            madctl: Orientation::Portrait as u8,
+            scan_direction: 0,
+            inverted: false,
+            init_inversion: true,
+            region_tracking: true,
+            ram_write_continuation: false,
+            window: None,
+            ram_write_open: false,
+            clock_adjuster: None,
+            write_continue: false,
+            software_brightness: 255,
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            circle_spans: None,
+            mirror: None,
+            mirror_cursor: None,
+            #[cfg(feature = "metrics")]
+            clock: None,
+            #[cfg(feature = "metrics")]
+            timing_stats: TimingStats::default(),
+            #[cfg(feature = "metrics")]
+            init_duration_us: None,
+            #[cfg(feature = "record")]
+            recorded: None,
         }
     }
 
@@ -142,12 +532,212 @@ where
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    pub fn init<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), ()>
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn init<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        self.init_with_delay(delay, DEFAULT_STABILIZE_MS)
+    }
+
+    /// Initializes the display with a custom final stabilization delay.
+    ///
+    /// This sends the same sequence as `init`, which waits 200ms after turning the display
+    /// on. Panels that settle faster can use a shorter delay to cut boot time.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - Delay provider.
+    /// * `stabilize_ms` - Time to wait after the display is turned on, in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn init_with_delay<DELAY>(
+        &mut self,
+        delay: &mut DELAY,
+        stabilize_ms: u32,
+    ) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        #[cfg(feature = "metrics")]
+        let started = self.clock.map(|clock| clock());
+
+        self.hard_reset(delay)?;
+        self.write_init_sequence(delay, stabilize_ms)?;
+
+        #[cfg(feature = "metrics")]
+        self.record_init_duration(started);
+
+        Ok(())
+    }
+
+    /// Resets the display with the software reset command and initializes it again.
+    ///
+    /// This recovers the panel after a fault without toggling the reset pin, for boards
+    /// where RST is shared or not connected. It waits the 120ms the controller needs after
+    /// SWRESET, then sends the same sequence as `init`. The driver is not initialized until
+    /// the sequence completes.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - Delay provider.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn reset_and_init<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        #[cfg(feature = "metrics")]
+        let started = self.clock.map(|clock| clock());
+
+        self.initialized = false;
+        self.write_command(Instruction::SwReset as u8, &[])?; // Software Reset (SWRESET)
+
+        // The reset also drops the panel's address window and any open memory write
+        self.window = None;
+        self.ram_write_open = false;
+        delay.delay_ms(120);
+        self.write_init_sequence(delay, DEFAULT_STABILIZE_MS)?;
+
+        #[cfg(feature = "metrics")]
+        self.record_init_duration(started);
+
+        Ok(())
+    }
+
+    /// Sends the full init sequence to a freshly reset display.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - Delay provider.
+    /// * `stabilize_ms` - Time to wait after the display is turned on, in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_init_sequence<DELAY>(
+        &mut self,
+        delay: &mut DELAY,
+        stabilize_ms: u32,
+    ) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        self.write_register_enables()?;
+        self.write_command(Instruction::DisSet5 as u8, &[0x00, 0x20])?; // Display Function Control (0xB6)
+
+        // Memory Access Control (MADCTL), with the BGR bit set only for BGR panels
+        let madctl = if self.rgb { 0x90 } else { 0x98 };
+        self.write_command(Instruction::MadCtl as u8, &[madctl])?;
+        self.madctl = madctl;
+        self.scan_direction = 0;
+        self.write_command(Instruction::ColMod as u8, &[0x05])?; // Pixel Format Set (COLMOD)
+        self.write_power_and_gamma()?;
+        self.write_command(Instruction::CaSet as u8, &[])?;
+        self.set_inversion(self.init_inversion)?; // Display Inversion ON/OFF (INVON/INVOFF)
+        self.write_command(Instruction::SlpOut as u8, &[])?; // Sleep Out Mode (SLPOUT)
+        self.write_command(Instruction::DispOn as u8, &[])?; // Display ON (DISPON)
+
+        delay.delay_ms(stabilize_ms);
+        self.initialized = true;
+        self.settle_ms = DEFAULT_STABILIZE_MS.saturating_sub(stabilize_ms);
+
+        Ok(())
+    }
+
+    /// Initializes the display with only the registers needed to show pixels.
+    ///
+    /// This is the first phase of a fast boot: it performs a hard reset, sleep out, pixel
+    /// format, memory access control, display function control, inversion and display on,
+    /// so a splash screen can be drawn right away. The vendor gamma and power tuning
+    /// registers sent by `init` are deferred to `finish_init`; until then colors and
+    /// contrast may be slightly off.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - Delay provider.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn init_fast<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), Error>
     where
         DELAY: DelayNs,
     {
+        #[cfg(feature = "metrics")]
+        let started = self.clock.map(|clock| clock());
+
         self.hard_reset(delay)?;
+        self.write_command(Instruction::SlpOut as u8, &[])?; // Sleep Out Mode (SLPOUT)
+        delay.delay_ms(120);
+        self.write_command(Instruction::ColMod as u8, &[0x05])?; // Pixel Format Set (COLMOD)
+        let madctl = if self.rgb { 0x90 } else { 0x98 };
+        self.write_command(Instruction::MadCtl as u8, &[madctl])?; // Memory Access Control (MADCTL)
+        self.madctl = madctl;
+        self.scan_direction = 0;
+        self.write_command(Instruction::DisSet5 as u8, &[0x00, 0x20])?; // Display Function Control (0xB6)
+        self.set_inversion(self.init_inversion)?; // Display Inversion ON/OFF (INVON/INVOFF)
+        self.write_command(Instruction::DispOn as u8, &[])?; // Display ON (DISPON)
+
+        delay.delay_ms(20);
+        self.initialized = true;
+        self.settle_ms = DEFAULT_STABILIZE_MS - 20;
+
+        #[cfg(feature = "metrics")]
+        self.record_init_duration(started);
+
+        Ok(())
+    }
+
+    /// Sends the tuning registers deferred by `init_fast`.
+    ///
+    /// This is the second phase of a fast boot. It programs the inter-register enables, the
+    /// vendor power and timing registers (0x84-0x8F, 0x90, 0xBC-0xBE, 0xFF, 0xED, 0xAE,
+    /// 0xCD, 0x62-0x74, 0x98), the voltage regulators (0xC3, 0xC4, 0xC9), the gamma tables
+    /// (0xE0, 0xE1, 0xF0-0xF3, 0xDF) and the frame rate (0xB1), exactly as `init` does.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - Delay provider.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn finish_init<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        self.ensure_initialized()?;
+        self.write_tuning_registers()?;
+
+        // Give the regulators time to settle at their new levels
+        delay.delay_ms(20);
+
+        Ok(())
+    }
+
+    /// Writes the vendor power, timing and gamma registers shared by `init` and
+    /// `finish_init`.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_tuning_registers(&mut self) -> Result<(), Error> {
+        self.write_register_enables()?;
+        self.write_power_and_gamma()
+    }
+
+    /// Writes the inter-register enables and the vendor timing registers 0x84-0x8F, which
+    /// `init` sends before the display function, memory access and pixel format commands.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_register_enables(&mut self) -> Result<(), Error> {
         self.write_command(0xEF, &[])?; // Inter Register Enable 2 (0xEF)
         self.write_command(0xEB, &[0x14])?;
         self.write_command(0xFE, &[])?; // Inter Register Enable 1 (0xFE)
@@ -165,9 +755,17 @@ where
         self.write_command(0x8D, &[0x01])?;
         self.write_command(0x8E, &[0xFF])?;
         self.write_command(0x8F, &[0xFF])?;
-        self.write_command(Instruction::DisSet5 as u8, &[0x00, 0x20])?; // Display Function Control (0xB6)
-        self.write_command(Instruction::MadCtl as u8, &[0x98])?; // Memory Access Control (MADCTL)
-        self.write_command(Instruction::ColMod as u8, &[0x05])?; // Pixel Format Set (COLMOD)
+
+        Ok(())
+    }
+
+    /// Writes the vendor power registers, voltage regulators, gamma tables and frame rate
+    /// that `init` sends after the pixel format.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_power_and_gamma(&mut self) -> Result<(), Error> {
         self.write_command(0x90, &[0x08, 0x08, 0x08, 0x08])?;
         self.write_command(0xBD, &[0x06])?;
         self.write_command(0xBC, &[0x00])?;
@@ -216,12 +814,48 @@ where
         )?;
         self.write_command(0x74, &[0x10, 0x85, 0x80, 0x00, 0x00, 0x4E, 0x00])?;
         self.write_command(0x98, &[0x3E, 0x07])?;
-        self.write_command(Instruction::CaSet as u8, &[])?;
-        self.write_command(Instruction::InvOn as u8, &[])?; // Display Inversion ON (INVON)
+
+        Ok(())
+    }
+
+    /// Initializes the display with only the standard MIPI commands.
+    ///
+    /// This skips the undocumented vendor registers sent by `init` and only performs a hard
+    /// reset, sleep out, pixel format, memory access control, inversion and display on. It
+    /// is meant for bring-up, to find out whether a module needs the vendor block at all.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - Delay provider.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn init_minimal<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        #[cfg(feature = "metrics")]
+        let started = self.clock.map(|clock| clock());
+
+        self.hard_reset(delay)?;
         self.write_command(Instruction::SlpOut as u8, &[])?; // Sleep Out Mode (SLPOUT)
+        delay.delay_ms(120);
+        self.write_command(Instruction::ColMod as u8, &[0x05])?; // Pixel Format Set (COLMOD)
+        let madctl = if self.rgb { 0x90 } else { 0x98 };
+        self.write_command(Instruction::MadCtl as u8, &[madctl])?; // Memory Access Control (MADCTL)
+        self.madctl = madctl;
+        self.scan_direction = 0;
+        self.set_inversion(self.init_inversion)?; // Display Inversion ON/OFF (INVON/INVOFF)
+        self.write_command(Instruction::NorOn as u8, &[])?; // Normal Display Mode ON (NORON)
         self.write_command(Instruction::DispOn as u8, &[])?; // Display ON (DISPON)
 
-        delay.delay_ms(200);
+        delay.delay_ms(20);
+        self.initialized = true;
+        self.settle_ms = DEFAULT_STABILIZE_MS - 20;
+
+        #[cfg(feature = "metrics")]
+        self.record_init_duration(started);
 
         Ok(())
     }
@@ -229,7 +863,8 @@ where
     /// Performs a hard reset of the display.
     ///
     /// This function performs a hard reset by toggling the reset pin, ensuring the display
-    /// is in a known state before initialization.
+    /// is in a known state before initialization. The pulse timing can be changed with
+    /// `set_reset_timing`.
     ///
     /// # Arguments
     ///
@@ -237,594 +872,5108 @@ where
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    pub fn hard_reset<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), ()>
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn hard_reset<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), Error>
     where
         DELAY: DelayNs,
     {
-        self.rst.set_high().map_err(|_| ())?;
-        delay.delay_ms(10);
-        self.rst.set_low().map_err(|_| ())?;
-        delay.delay_ms(10);
-        self.rst.set_high().map_err(|_| ())?;
-        delay.delay_ms(10);
+        self.initialized = false;
+        self.ram_write_open = false;
+        self.window = None;
+        self.rst.set_high().map_err(|_| Error::Pin)?;
+        delay.delay_ms(self.reset_pre_ms);
+        self.rst.set_low().map_err(|_| Error::Pin)?;
+        delay.delay_ms(self.reset_low_ms);
+        self.rst.set_high().map_err(|_| Error::Pin)?;
+        delay.delay_ms(self.reset_post_ms);
 
         Ok(())
     }
 
-    /// Writes a command to the display.
+    /// Returns whether `init` has completed since the last reset.
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Waits until the panel has settled after initialization.
     ///
-    /// This function sends a command followed by optional parameters to the display.
+    /// `init` waits 200ms after turning the display on, which is enough for the first frame
+    /// to show cleanly. `init_with_delay` with a shorter delay, `init_fast` and
+    /// `init_minimal` return sooner; calling this before the first draw waits out the rest
+    /// of that time. It returns immediately if there is nothing left to wait for, so it is
+    /// safe to call after any init.
     ///
     /// # Arguments
     ///
-    /// * `command` - Command to write.
-    /// * `params` - Parameters for the command.
+    /// * `delay` - Delay provider.
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    fn write_command(&mut self, command: u8, params: &[u8]) -> Result<(), ()> {
-        self.cs.set_high().map_err(|_| ())?;
-        self.dc.set_low().map_err(|_| ())?;
-        self.cs.set_low().map_err(|_| ())?;
-        self.spi.write(&[command]).map_err(|_| ())?;
-        if !params.is_empty() {
-            self.start_data()?;
-            self.write_data(params)?;
+    /// `Result<(), Error>` indicating success, or `Error::NotInitialized` if the display
+    /// has not been initialized.
+    pub fn wait_ready<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        self.ensure_initialized()?;
+
+        if self.settle_ms > 0 {
+            delay.delay_ms(self.settle_ms);
+            self.settle_ms = 0;
         }
-        self.cs.set_high().map_err(|_| ())?;
+
         Ok(())
     }
 
-    /// Starts data transmission.
+    /// Puts the panel into sleep mode with SLPIN (0x10).
     ///
-    /// Sets the data/command pin to indicate data mode for subsequent transmissions.
+    /// The panel needs 5ms before it accepts the next command; poll `is_ready` with the
+    /// same clock instead of blocking.
+    ///
+    /// # Arguments
+    ///
+    /// * `now_us` - Current time from a monotonic microsecond clock.
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    fn start_data(&mut self) -> Result<(), ()> {
-        self.dc.set_high().map_err(|_| ())
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn sleep(&mut self, now_us: u32) -> Result<(), Error> {
+        self.write_command(Instruction::SlpIn as u8, &[])?;
+        self.settling = Some((now_us, SLEEP_IN_SETTLE_US));
+        Ok(())
     }
 
-    /// Writes data to the display.
+    /// Wakes the panel from sleep mode with SLPOUT (0x11).
     ///
-    /// This function writes data to the display through the SPI interface.
+    /// The panel needs 120ms before frames drawn to it show without artifacts; poll
+    /// `is_ready` with the same clock instead of blocking.
     ///
     /// # Arguments
     ///
-    /// * `data` - Data to write.
+    /// * `now_us` - Current time from a monotonic microsecond clock.
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    fn write_data(&mut self, data: &[u8]) -> Result<(), ()> {
-        self.cs.set_high().map_err(|_| ())?;
-        self.dc.set_high().map_err(|_| ())?;
-        self.cs.set_low().map_err(|_| ())?;
-        self.spi.write(data).map_err(|_| ())?;
-        self.cs.set_high().map_err(|_| ())?;
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn wake(&mut self, now_us: u32) -> Result<(), Error> {
+        self.write_command(Instruction::SlpOut as u8, &[])?;
+        self.settling = Some((now_us, SLEEP_OUT_SETTLE_US));
         Ok(())
     }
 
-    /// Writes a data word to the display.
+    /// Returns whether the last `sleep` or `wake` transition has settled.
     ///
-    /// This function writes a 16-bit word to the display.
+    /// The time elapsed since the transition is compared with its settle time using
+    /// wrapping arithmetic, so a free-running 32-bit counter can be used directly. Once
+    /// the settle time has passed the transition is forgotten, so a clock that wraps
+    /// around during a long sleep cannot make it look pending again. Commands sent in
+    /// the meantime do not shorten the wait.
     ///
     /// # Arguments
     ///
-    /// * `value` - Data word to write.
+    /// * `now_us` - Current time from the clock passed to `sleep` and `wake`.
+    pub fn is_ready(&mut self, now_us: u32) -> bool {
+        if let Some((started, settle_us)) = self.settling {
+            if now_us.wrapping_sub(started) < settle_us {
+                return false;
+            }
+            self.settling = None;
+        }
+        true
+    }
+
+    /// Returns `Error::NotInitialized` if `init` has not completed yet.
+    fn ensure_initialized(&self) -> Result<(), Error> {
+        if self.initialized {
+            Ok(())
+        } else {
+            Err(Error::NotInitialized)
+        }
+    }
+
+    /// Sets the clock used to time init and the `show` and `show_region` calls.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    fn write_word(&mut self, value: u16) -> Result<(), ()> {
-        self.write_data(&value.to_be_bytes())
+    /// * `clock` - Function returning a monotonic time in microseconds. Wrapping is
+    ///   handled, so a free-running 32-bit counter can be used directly.
+    #[cfg(feature = "metrics")]
+    pub fn with_clock(mut self, clock: fn() -> u32) -> Self {
+        self.clock = Some(clock);
+        self
     }
 
-    /// Sets the orientation of the display.
+    /// Returns the transfer durations collected since the last reset.
+    #[cfg(feature = "metrics")]
+    pub fn timing_stats(&self) -> TimingStats {
+        self.timing_stats
+    }
+
+    /// Clears the collected transfer durations.
+    #[cfg(feature = "metrics")]
+    pub fn reset_timing_stats(&mut self) {
+        self.timing_stats = TimingStats::default();
+    }
+
+    /// Returns how long the last completed `init`, `init_with_delay`, `init_fast`,
+    /// `init_minimal` or `reset_and_init` took, including the reset and settle delays.
     ///
-    /// This function sets the display orientation to one of the predefined modes.
+    /// This is `None` until an init completes with a clock set by `with_clock`.
+    #[cfg(feature = "metrics")]
+    pub fn init_duration_us(&self) -> Option<u32> {
+        self.init_duration_us
+    }
+
+    /// Records the duration of an init that started at `started`.
+    #[cfg(feature = "metrics")]
+    fn record_init_duration(&mut self, started: Option<u32>) {
+        if let (Some(clock), Some(started)) = (self.clock, started) {
+            self.init_duration_us = Some(clock().wrapping_sub(started));
+        }
+    }
+
+    /// Records the duration of a transfer that started at `started`.
+    #[cfg(feature = "metrics")]
+    fn record_timing(&mut self, started: Option<u32>) {
+        if let (Some(clock), Some(started)) = (self.clock, started) {
+            self.timing_stats.record(clock().wrapping_sub(started));
+        }
+    }
+
+    /// Sets the timing used by `hard_reset`.
+    ///
+    /// The default is 10ms for each phase. Some modules need a longer low pulse to reset
+    /// reliably.
     ///
     /// # Arguments
     ///
-    /// * `orientation` - Orientation to set.
+    /// * `pre_ms` - Time to hold the reset pin high before the pulse.
+    /// * `low_ms` - Duration of the low reset pulse.
+    /// * `post_ms` - Time to wait after releasing the reset pin.
+    pub fn set_reset_timing(&mut self, pre_ms: u32, low_ms: u32, post_ms: u32) {
+        self.reset_pre_ms = pre_ms;
+        self.reset_low_ms = low_ms;
+        self.reset_post_ms = post_ms;
+    }
+
+    /// Sets whether the init sequences turn display inversion on.
+    ///
+    /// The default is on, which gives correct colors on most GC9A01A modules. Modules that
+    /// show inverted colors with it should turn it off here, before `init`, rather than
+    /// calling `set_inversion` afterward, which causes a visible flash.
+    ///
+    /// # Arguments
+    ///
+    /// * `inverted` - Whether `init`, `init_with_delay`, `init_fast` and `init_minimal`
+    ///   send INVON (true) or INVOFF (false).
+    pub fn set_init_inversion(&mut self, inverted: bool) {
+        self.init_inversion = inverted;
+    }
+
+    /// Writes a command to the display.
+    ///
+    /// This function sends a command followed by optional parameters to the display.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - Command to write.
+    /// * `params` - Parameters for the command.
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    pub fn set_orientation(&mut self, orientation: &Orientation) -> Result<(), ()> {
-        if self.rgb {
-            self.write_command(Instruction::MadCtl as u8, &[*orientation as u8])?;
-        } else {
-            self.write_command(Instruction::MadCtl as u8, &[*orientation as u8 | 0x08])?;
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_command(&mut self, command: u8, params: &[u8]) -> Result<(), Error> {
+        let ram_write = command == Instruction::RamWr as u8;
+        if ram_write && self.ram_write_continuation && self.ram_write_open {
+            // Memory writes continue from where the previous one wrapped around
+            return Ok(());
+        }
+        self.ram_write_open = ram_write || command == Instruction::RamWrCont as u8;
+        if command == Instruction::CaSet as u8 || command == Instruction::RaSet as u8 {
+            self.window = None;
+            self.mirror_cursor = None;
         }
+        if ram_write {
+            // A memory write starts again at the top left of the window
+            if let Some((_, position)) = self.mirror_cursor.as_mut() {
+                *position = 0;
+            }
+        }
+
+        self.cs.set_high().map_err(|_| Error::Pin)?;
+        self.dc.set_low().map_err(|_| Error::Pin)?;
+        self.cs.set_low().map_err(|_| Error::Pin)?;
+        self.spi_write(&[command])?;
+        if !params.is_empty() {
+            self.start_data()?;
+            self.write_data(params)?;
+        }
+        self.cs.set_high().map_err(|_| Error::Pin)?;
         Ok(())
     }
 
-    /// Sets the global offset of the displayed image.
+    /// Sends bytes over SPI, or captures them while recording is enabled.
     ///
     /// # Arguments
     ///
-    /// * `dx` - Horizontal offset.
-    /// * `dy` - Vertical offset.
-    pub fn set_offset(&mut self, dx: u16, dy: u16) {
-        self.dx = dx;
-        self.dy = dy;
+    /// * `data` - Bytes to send.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn spi_write(&mut self, data: &[u8]) -> Result<(), Error> {
+        #[cfg(feature = "record")]
+        if let Some(recorded) = self.recorded.as_mut() {
+            recorded.extend_from_slice(data);
+            return Ok(());
+        }
+        if self.spi.write(data).is_err() {
+            return Err(self.abort_transfer());
+        }
+        Ok(())
     }
 
-    /// Sets the address window for the display.
+    /// Releases chip select after a failed transfer so the next command starts cleanly.
     ///
-    /// This function sets the address window for subsequent drawing commands.
+    /// # Returns
+    ///
+    /// `Error::Spi`, for the caller to return.
+    fn abort_transfer(&mut self) -> Error {
+        // The SPI failure is what gets reported; a pin error here would only mask it
+        let _ = self.cs.set_high();
+        Error::Spi
+    }
+
+    /// Enables or disables recording mode.
+    ///
+    /// While recording, every byte that would be sent over SPI (commands, parameters and
+    /// pixel data) is appended to an internal buffer instead, so the exact command stream
+    /// of an operation such as `init` can be inspected without hardware. Disabling
+    /// recording discards anything not yet taken.
     ///
     /// # Arguments
     ///
-    /// * `start_x` - Start x-coordinate.
-    /// * `start_y` - Start y-coordinate.
-    /// * `end_x` - End x-coordinate.
-    /// * `end_y` - End y-coordinate.
+    /// * `enabled` - Whether to record instead of transmitting.
+    #[cfg(feature = "record")]
+    pub fn set_recording(&mut self, enabled: bool) {
+        self.recorded = if enabled {
+            Some(alloc::vec::Vec::new())
+        } else {
+            None
+        };
+    }
+
+    /// Returns the bytes recorded so far.
+    #[cfg(feature = "record")]
+    pub fn recorded(&self) -> &[u8] {
+        self.recorded.as_deref().unwrap_or(&[])
+    }
+
+    /// Returns the bytes recorded so far and clears the recording buffer.
+    #[cfg(feature = "record")]
+    pub fn take_recorded(&mut self) -> alloc::vec::Vec<u8> {
+        self.recorded
+            .as_mut()
+            .map(core::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Sets a callback that changes the SPI clock around reads.
     ///
-    /// # Returns
+    /// The driver cannot change the bus clock itself, but reads such as `check_ready` and
+    /// `invert_region_on_panel` are only reliable well below the write clock. Before each
+    /// read the callback is called with `MAX_READ_HZ`, and afterwards with `MAX_WRITE_HZ`;
+    /// it should set the bus to the fastest clock it supports up to that frequency.
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    pub fn set_address_window(
-        &mut self,
-        start_x: u16,
-        start_y: u16,
-        end_x: u16,
-        end_y: u16,
-    ) -> Result<(), ()> {
-        self.write_command(Instruction::CaSet as u8, &[])?;
-        self.start_data()?;
-        self.write_word(start_x + self.dx)?;
-        self.write_word(end_x + self.dx)?;
-        self.write_command(Instruction::RaSet as u8, &[])?;
-        self.start_data()?;
-        self.write_word(start_y + self.dy)?;
-        self.write_word(end_y + self.dy)
+    /// # Arguments
+    ///
+    /// * `adjuster` - Function called with the desired SPI clock in Hz.
+    pub fn set_clock_adjuster(&mut self, adjuster: fn(u32)) {
+        self.clock_adjuster = Some(adjuster);
     }
 
-    /// Clears the screen by filling it with a single color.
+    /// Sends a command and reads back its response.
     ///
-    /// This function sets the entire display to the specified color by writing data
-    /// in chunks, which balances memory efficiency and performance.
+    /// The response is read with the data/command pin high, after the command byte, while
+    /// chip select stays asserted. This requires an SPI bus with a working MISO line.
     ///
     /// # Arguments
     ///
-    /// * `color` - The color to fill the screen with, in RGB565 format.
+    /// * `command` - Command to send.
+    /// * `buffer` - Buffer to fill with the response, including any dummy bytes.
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    pub fn clear_screen(&mut self, color: u16) -> Result<(), ()> {
-        let color_high = (color >> 8) as u8;
-        let color_low = (color & 0xff) as u8;
-
-        // Set the address window to cover the entire screen
-        self.set_address_window(0, 0, self.width as u16 - 1, self.height as u16 - 1)?;
-        self.write_command(Instruction::RamWr as u8, &[])?;
-        self.start_data()?;
-
-        // Define a constant for the chunk size
-        const CHUNK_SIZE: usize = 512;
-        let mut chunk = [0u8; CHUNK_SIZE * 2];
-
-        // Fill the chunk with the color data
-        for i in 0..CHUNK_SIZE {
-            chunk[i * 2] = color_high;
-            chunk[i * 2 + 1] = color_low;
+    /// `Result<(), Error>` indicating success or failure.
+    fn read_command(&mut self, command: u8, buffer: &mut [u8]) -> Result<(), Error> {
+        if let Some(adjust) = self.clock_adjuster {
+            adjust(Self::MAX_READ_HZ);
         }
-
-        // Write data in chunks
-        let total_pixels = (self.width * self.height) as usize;
-        let full_chunks = total_pixels / CHUNK_SIZE;
-        let remaining_pixels = total_pixels % CHUNK_SIZE;
-
-        for _ in 0..full_chunks {
-            self.write_data(&chunk)?;
+        let result = self.read_transfer(command, buffer);
+        // Restore the write clock even if the read failed
+        if let Some(adjust) = self.clock_adjuster {
+            adjust(Self::MAX_WRITE_HZ);
         }
+        result
+    }
 
-        if remaining_pixels > 0 {
-            self.write_data(&chunk[0..(remaining_pixels * 2)])?;
+    /// Performs the bus transfer of `read_command`.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - Command to send.
+    /// * `buffer` - Buffer to fill with the response, including any dummy bytes.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn read_transfer(&mut self, command: u8, buffer: &mut [u8]) -> Result<(), Error> {
+        self.ram_write_open = false;
+        self.cs.set_high().map_err(|_| Error::Pin)?;
+        self.dc.set_low().map_err(|_| Error::Pin)?;
+        self.cs.set_low().map_err(|_| Error::Pin)?;
+        self.spi_write(&[command])?;
+        self.dc.set_high().map_err(|_| Error::Pin)?;
+        if self.spi.read(buffer).is_err() {
+            return Err(self.abort_transfer());
         }
-
+        self.cs.set_high().map_err(|_| Error::Pin)?;
         Ok(())
     }
 
-    /// Sets a pixel color at the given coordinates.
+    /// Starts data transmission.
     ///
-    /// This function sets the color of a single pixel at the specified coordinates.
+    /// Sets the data/command pin to indicate data mode for subsequent transmissions.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn start_data(&mut self) -> Result<(), Error> {
+        self.dc.set_high().map_err(|_| Error::Pin)
+    }
+
+    /// Writes data to the display.
+    ///
+    /// This function writes data to the display through the SPI interface. Pixel data
+    /// sent after RamWr or RamWrCont is also copied into the mirror.
     ///
     /// # Arguments
     ///
-    /// * `x` - X-coordinate.
-    /// * `y` - Y-coordinate.
-    /// * `color` - Color of the pixel.
+    /// * `data` - Data to write.
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    pub fn write_pixel(&mut self, x: u16, y: u16, color: u16) -> Result<(), ()> {
-        self.set_address_window(x, y, x, y)?;
-        self.write_command(Instruction::RamWr as u8, &[])?;
-        self.start_data()?;
-        self.write_word(color)
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_data(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.send_data(data)?;
+        if self.ram_write_open {
+            self.mirror_stream(data);
+        }
+        Ok(())
     }
 
-    /// Draws an image from a slice of RGB565 data.
-    ///
-    /// This function draws an image from a slice of pixel data in RGB565 format.
-    /// It assumes the image dimensions match the display dimensions.
+    /// Sends data to the display without updating the mirror.
     ///
     /// # Arguments
     ///
-    /// * `image_data` - Image data to draw.
+    /// * `data` - Data to write.
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    pub fn draw_image(&mut self, image_data: &[u8]) -> Result<(), ()> {
-        let width = self.width as u16;
-        let height = self.height as u16;
-
-        self.set_address_window(0, 0, width - 1, height - 1)?;
-        self.write_command(Instruction::RamWr as u8, &[])?;
-        self.start_data()?;
+    /// `Result<(), Error>` indicating success or failure.
+    fn send_data(&mut self, data: &[u8]) -> Result<(), Error> {
+        self.cs.set_high().map_err(|_| Error::Pin)?;
+        self.dc.set_high().map_err(|_| Error::Pin)?;
+        self.cs.set_low().map_err(|_| Error::Pin)?;
+        self.spi_write(data)?;
+        self.cs.set_high().map_err(|_| Error::Pin)?;
+        Ok(())
+    }
 
-        for chunk in image_data.chunks(32) {
-            self.write_data(chunk)?;
+    /// Writes one chunk of a memory write started with RamWr.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Pixel data to write.
+    /// * `first` - Whether this is the first chunk after RamWr.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_ram_chunk(&mut self, data: &[u8], first: bool) -> Result<(), Error> {
+        if !first && self.write_continue {
+            self.write_command(Instruction::RamWrCont as u8, &[])?;
         }
-
-        Ok(())
+        self.write_data(data)
     }
 
-    /// Displays the provided buffer on the screen.
+    /// Sets a brightness applied in software to every frame sent by `show` and
+    /// `show_region`, for boards without a PWM backlight.
     ///
-    /// This function writes the entire buffer to the display, assuming the buffer
-    /// contains pixel data for the full display area.
+    /// Below 255, each pixel's channels are scaled through a lookup table and copied
+    /// through a 512-byte buffer before being sent. That costs CPU time on every pixel of
+    /// every frame, about 57600 pixels for a full 240x240 `show`, and splits `show` into
+    /// 512-byte transfers. Solid fills such as `clear_screen` and `fill_rect`, and images
+    /// drawn with `draw_image`, are sent unchanged.
     ///
     /// # Arguments
     ///
-    /// * `buffer` - Buffer to display.
+    /// * `level` - Brightness from 0 (black) to 255 (unchanged, the default).
+    pub fn set_software_brightness(&mut self, level: u8) {
+        self.software_brightness = level;
+    }
+
+    /// Sends pixel data scaled by the software brightness.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Pixel data in RGB565 format.
+    /// * `first` - Whether this is the first data after RamWr.
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success or failure.
-    pub fn show(&mut self, buffer: &[u8]) -> Result<(), ()> {
-        self.write_command(Instruction::CaSet as u8, &[])?;
-        self.write_data(&[0x00, 0x00, 0x00, 0xEF])?;
-
-        self.write_command(Instruction::RaSet as u8, &[])?;
-        self.write_data(&[0x00, 0x00, 0x00, 0xEF])?;
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_dimmed(&mut self, data: &[u8], first: bool) -> Result<(), Error> {
+        const CHUNK_SIZE: usize = 512;
+        let level = self.software_brightness as u32;
 
-        self.write_command(Instruction::RamWr as u8, &[])?;
+        // Scaled values for every 5-bit and 6-bit channel value
+        let mut lut5 = [0u16; 32];
+        let mut lut6 = [0u16; 64];
+        for (i, value) in lut5.iter_mut().enumerate() {
+            *value = (i as u32 * level / 255) as u16;
+        }
+        for (i, value) in lut6.iter_mut().enumerate() {
+            *value = (i as u32 * level / 255) as u16;
+        }
 
-        self.cs.set_high().map_err(|_| ())?;
-        self.dc.set_high().map_err(|_| ())?;
-        self.cs.set_low().map_err(|_| ())?;
-        self.spi.write(buffer).map_err(|_| ())?;
-        self.cs.set_high().map_err(|_| ())?;
+        let mut scaled = [0u8; CHUNK_SIZE];
+        for (i, chunk) in data.chunks(CHUNK_SIZE).enumerate() {
+            for (dst, src) in scaled.chunks_exact_mut(2).zip(chunk.chunks_exact(2)) {
+                let pixel = u16::from_be_bytes([src[0], src[1]]);
+                let pixel = lut5[(pixel >> 11) as usize] << 11
+                    | lut6[((pixel >> 5) & 0x3F) as usize] << 5
+                    | lut5[(pixel & 0x1F) as usize];
+                dst.copy_from_slice(&pixel.to_be_bytes());
+            }
+            if !(first && i == 0) && self.write_continue {
+                self.write_command(Instruction::RamWrCont as u8, &[])?;
+            }
+            self.send_data(&scaled[..chunk.len()])?;
+            // The mirror keeps the pixels as given, before the brightness is applied
+            self.mirror_stream(chunk);
+        }
 
         Ok(())
     }
 
-    /// Updates only the specified region of the display with the provided buffer.
+    /// Keeps a copy of the on-screen content in a driver-owned buffer.
     ///
-    /// This function updates a specified rectangular region of the display with the pixel data
-    /// provided in the buffer. It calculates the necessary offsets and addresses to update only
-    /// the designated area, ensuring efficient display refresh.
+    /// Many boards do not connect MISO, so the panel cannot be read back. With a mirror,
+    /// every pixel written to display memory is also written into the buffer, and
+    /// `snapshot` returns them, for example as the starting frame of a transition. This
+    /// costs a full frame of RAM and a copy of every pixel sent.
+    ///
+    /// The driver follows the window set by `set_address_window` the way the controller
+    /// does, so all drawing methods, including embedded-graphics drawing, images, pixel
+    /// writers and `GC9A01ABatched`, are reflected. Writes to a window set with
+    /// `set_raw_window` are not, since its coordinates ignore the offset and orientation.
+    /// The mirror holds the pixels as given, before any software brightness. The buffer
+    /// starts out as it is passed, so it should already match the panel.
+    ///
+    /// The driver keeps the buffer for as long as it exists and has no lifetime parameter,
+    /// so the buffer must be `'static`. On embedded targets the usual way to get one
+    /// without `unsafe` is a `static MIRROR: StaticCell<[u8; 115200]>` from the
+    /// `static_cell` crate, passing `MIRROR.init([0; 115200])` here. With `alloc`
+    /// available, `Vec::leak` works too. `disable_mirror` hands the buffer back for other
+    /// uses.
     ///
     /// # Arguments
     ///
-    /// * `buffer` - A slice of bytes representing the pixel data in RGB565 format.
-    /// * `top_left_x` - The x-coordinate of the top-left corner of the region to update.
-    /// * `top_left_y` - The y-coordinate of the top-left corner of the region to update.
-    /// * `width` - The width of the region to update.
-    /// * `height` - The height of the region to update.
+    /// * `buffer` - Storage for the mirror, at least `width * height * 2` bytes.
     ///
     /// # Returns
     ///
-    /// `Result<(), ()>` indicating success (`Ok`) or failure (`Err`).
-    pub fn show_region(
-        &mut self,
-        buffer: &[u8],
-        top_left_x: u16,
-        top_left_y: u16,
-        width: u32,
-        height: u32,
-    ) -> Result<(), ()> {
-        let start_x = top_left_x as u16; // Start x-coordinate
-        let start_y = top_left_y as u16; // Start y-coordinate
-        let end_x = (top_left_x as u32 + width - 1) as u16; // End x-coordinate
-        let end_y = (top_left_y as u32 + height - 1) as u16; // End y-coordinate
+    /// `Result<(), Error>` indicating success, or `Error::BufferSize` if the buffer is too
+    /// small.
+    pub fn enable_mirror(&mut self, buffer: &'static mut [u8]) -> Result<(), Error> {
+        let size = self.width as usize * self.height as usize * BYTES_PER_PIXEL;
+        if buffer.len() < size {
+            return Err(Error::BufferSize);
+        }
 
-        // Calculate the buffer offset for the region
-        let buffer_width = self.width as usize; // Width of the buffer
-        let bytes_per_pixel = 2; // Number of bytes per pixel in RGB565 format
+        self.mirror = Some(&mut buffer[..size]);
+        Ok(())
+    }
 
-        // Set the address window for the region to be updated
-        self.set_address_window(start_x, start_y, end_x, end_y)?;
+    /// Stops mirroring and hands back the mirror buffer.
+    ///
+    /// # Returns
+    ///
+    /// The buffer passed to `enable_mirror`, trimmed to the screen size, or `None` if
+    /// mirroring was not enabled.
+    pub fn disable_mirror(&mut self) -> Option<&'static mut [u8]> {
+        self.mirror.take()
+    }
 
-        // Send the command to write to RAM
-        self.write_command(Instruction::RamWr as u8, &[])?;
+    /// Returns the on-screen content recorded by the mirror.
+    ///
+    /// # Returns
+    ///
+    /// The content in RGB565 format, row by row for the full screen, or an empty slice if
+    /// mirroring is not enabled.
+    pub fn snapshot(&self) -> &[u8] {
+        self.mirror.as_deref().unwrap_or(&[])
+    }
 
-        // Start data transmission
-        self.start_data()?;
+    /// Copies pixel data being written to the panel into the mirror.
+    ///
+    /// The data lands at the cursor set up by `set_address_window`, which moves through
+    /// the window row by row and wraps back to its start like the controller's write
+    /// pointer. Nothing is copied if the mirror is disabled or the window was set some
+    /// other way, such as with `set_raw_window`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Pixel data in RGB565 format.
+    fn mirror_stream(&mut self, mut data: &[u8]) {
+        let (width, height) = (self.width as usize, self.height as usize);
+        let (Some(mirror), Some((region, position))) =
+            (self.mirror.as_deref_mut(), self.mirror_cursor.as_mut())
+        else {
+            return;
+        };
 
-        // Iterate over each row in the region
-        for y in start_y..=end_y {
-            let start_index = ((y as usize) * buffer_width + (start_x as usize)) * bytes_per_pixel;
-            let end_index = start_index + (width as usize) * bytes_per_pixel;
+        let row_bytes = region.width as usize * BYTES_PER_PIXEL;
+        let window_bytes = row_bytes * region.height as usize;
+        while !data.is_empty() {
+            *position %= window_bytes;
+            let row = *position / row_bytes;
+            let column = *position % row_bytes;
+            let run = (row_bytes - column).min(data.len());
 
-            // Write data to the display in chunks of 32 bytes
-            for chunk in buffer[start_index..end_index].chunks(32) {
-                self.write_data(chunk)?;
+            // Copy the part of the run that lies on the screen
+            let y = region.y as usize + row;
+            let start_x = region.x as usize * BYTES_PER_PIXEL + column;
+            let end_x = (start_x + run).min(width * BYTES_PER_PIXEL);
+            if y < height && start_x < end_x {
+                let offset = y * width * BYTES_PER_PIXEL;
+                mirror[offset + start_x..offset + end_x].copy_from_slice(&data[..end_x - start_x]);
             }
-        }
 
-        Ok(())
+            data = &data[run..];
+            *position += run;
+        }
     }
 
-    pub fn store_region(&mut self, region: Region) -> Result<(), ()> {
-        for i in 0..self.regions.len() {
-            if self.regions[i].is_none() {
-                self.regions[i] = Some(region);
-                return Ok(());
-            }
-        }
-        Err(())
+    /// Sets whether streamed writes explicitly continue with RamWrCont (0x3C).
+    ///
+    /// By default a memory write is started once with RamWr (0x2C) and the following
+    /// chunks are sent as plain data. With this enabled, `fill_rect`, `clear_screen`,
+    /// `draw_image_sized`, `draw_image` and `show` send RamWrCont before every chunk after
+    /// the first, which some controllers handle better for very large updates. `show`
+    /// is then split into chunks of 4096 bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to send RamWrCont between chunks.
+    pub fn set_write_continue(&mut self, enabled: bool) {
+        self.write_continue = enabled;
     }
 
-    pub fn store_region_from_params(
-        &mut self,
-        x: u16,
-        y: u16,
-        width: u32,
-        height: u32,
-    ) -> Result<(), ()> {
-        let region = Region {
-            x,
-            y,
-            width,
-            height,
-        };
-
-        self.store_region(region)
+    /// Writes a data word to the display.
+    ///
+    /// This function writes a 16-bit word to the display.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Data word to write.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_word(&mut self, value: u16) -> Result<(), Error> {
+        self.write_data(&color::pack_rgb565_be(value))
     }
 
-    pub fn get_regions(&self) -> &[Option<Region>] {
-        &self.regions
+    /// Sets how many bytes are sent per SPI write when streaming buffers.
+    ///
+    /// This applies to `draw_image`, `draw_image_sized`, `draw_image_centered` and
+    /// `show_region`. Larger chunks mean fewer transfers; smaller ones keep each transfer
+    /// short on shared buses.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - Bytes per write. Values below 2 are raised to one pixel.
+    pub fn set_chunk_size(&mut self, size: usize) {
+        self.chunk_size = size.max(2);
     }
 
-    pub fn clear_regions(&mut self) {
-        self.regions = [None; 10];
+    /// Returns the number of bytes each pixel takes in buffers sent to the display.
+    ///
+    /// Use this instead of hardcoding 2 when indexing buffers, so code keeps working if
+    /// other color depths are supported.
+    pub fn bytes_per_pixel(&self) -> usize {
+        BYTES_PER_PIXEL
     }
 
-    pub fn show_regions(&mut self, buffer: &[u8]) -> Result<(), ()> {
-        for i in 0..self.regions.len() {
-            if self.regions[i].is_some() {
-                if let Some(region_data) = self.regions[i] {
-                    self.show_region(
-                        buffer,
-                        region_data.x,
-                        region_data.y,
-                        region_data.width,
-                        region_data.height,
-                    )?;
-                }
-            }
-        }
-
-        Ok(())
+    /// Returns the number of bytes in one row of a full-screen buffer, in the current
+    /// orientation.
+    pub fn stride(&self) -> usize {
+        self.width as usize * BYTES_PER_PIXEL
     }
 
-    // Additional function with default parameter
-    pub fn show_regions_and_clear(&mut self, buffer: &[u8]) -> Result<(), ()> {
-        if let Err(e) = self.show_regions(buffer) {
-            // Handle the error, e.g., log it or return a different error
-            return Err(e);
+    /// Estimates how long sending a number of bytes takes at a given SPI clock.
+    ///
+    /// This only counts the bits on the wire; gaps between transfers and pin toggling add
+    /// to the real time. A full-screen `show` of a 240x240 display sends 115200 bytes.
+    ///
+    /// The driver does not know the SPI clock, since the bus is configured by the HAL
+    /// before it is handed over, so the frequency has to be passed in; the clock set with
+    /// `with_clock` is a time source, not the bus frequency. Nor does the driver count
+    /// the bytes it sends. To compare the estimate with reality, take the byte count from
+    /// the `record` feature and the measured durations from the `metrics` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Number of bytes to send.
+    /// * `spi_hz` - SPI clock frequency in Hz.
+    ///
+    /// # Returns
+    ///
+    /// The estimated time in microseconds, saturating at `u32::MAX` (also returned for a
+    /// zero clock).
+    pub fn estimate_transfer_us(&self, bytes: usize, spi_hz: u32) -> u32 {
+        if spi_hz == 0 {
+            return u32::MAX;
         }
-        self.clear_regions();
-        Ok(())
-    }
-}
 
-// Implementing the DrawTarget trait for the GC9A01A display driver
-impl<SPI, DC, CS, RST> DrawTarget for GC9A01A<SPI, DC, CS, RST>
-where
-    SPI: SpiDevice,
-    DC: OutputPin,
-    CS: OutputPin,
-    RST: OutputPin,
-{
-    type Color = Rgb565;
-    type Error = ();
+        let bits = bytes as u64 * 8;
+        let us = (bits * 1_000_000).div_ceil(spi_hz as u64);
+        u32::try_from(us).unwrap_or(u32::MAX)
+    }
 
-    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    /// Runs a closure with a large transfer chunk size, then restores the previous size.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Closure performing bulk operations such as `show_region`.
+    ///
+    /// # Returns
+    ///
+    /// The value returned by the closure.
+    pub fn with_bulk_transfer<F, R>(&mut self, f: F) -> R
     where
-        I: IntoIterator<Item = Pixel<Self::Color>>,
+        F: FnOnce(&mut Self) -> R,
     {
-        for Pixel(coord, color) in pixels.into_iter() {
-            let color_value = color.into_storage();
-            // Only draw pixels that would be on screen
-            if coord.x >= 0
-                && coord.y >= 0
-                && coord.x < self.width as i32
-                && coord.y < self.height as i32
-            {
-                self.write_pixel(coord.x as u16, coord.y as u16, color_value)?;
-            }
-        }
-        Ok(())
-    }
-}
-
-// Implementing the OriginDimensions trait for the GC9A01A display driver
-impl<SPI, DC, CS, RST> OriginDimensions for GC9A01A<SPI, DC, CS, RST>
-where
-    SPI: SpiDevice,
-    DC: OutputPin,
-    CS: OutputPin,
-    RST: OutputPin,
-{
-    fn size(&self) -> Size {
-        Size::new(self.width, self.height)
+        let previous = self.chunk_size;
+        self.chunk_size = BULK_CHUNK_SIZE;
+        let result = f(self);
+        self.chunk_size = previous;
+        result
     }
-}
 
-/// A structure representing a frame buffer.
-pub struct FrameBuffer<'a> {
-    buffer: &'a mut [u8],
-    width: u32,
-    height: u32,
-}
-
-impl<'a> FrameBuffer<'a> {
-    /// Creates a new frame buffer.
+    /// Sets the orientation of the display.
+    ///
+    /// This function sets the display orientation to one of the predefined modes.
     ///
     /// # Arguments
     ///
-    /// * `buffer` - A mutable slice representing the pixel data.
-    /// * `width` - The width of the frame buffer.
-    /// * `height` - The height of the frame buffer.
-    pub fn new(buffer: &'a mut [u8], width: u32, height: u32) -> Self {
-        Self {
-            buffer,
-            width,
-            height,
-        }
+    /// * `orientation` - Orientation to set.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn set_orientation(&mut self, orientation: &Orientation) -> Result<(), Error> {
+        let madctl = *orientation as u8;
+        self.set_madctl_flags(
+            madctl & MADCTL_MX != 0,
+            madctl & MADCTL_MY != 0,
+            madctl & MADCTL_MV != 0,
+            !self.rgb,
+        )
     }
 
-    /// Returns a reference to the buffer.
+    /// Sets the scan configuration from explicit flags.
+    ///
+    /// This gives access to all eight combinations of mirroring and axis exchange, not just
+    /// the four `Orientation` presets.
+    ///
+    /// # Arguments
+    ///
+    /// * `mirror_x` - Reverse the column address order (MX).
+    /// * `mirror_y` - Reverse the row address order (MY).
+    /// * `swap_xy` - Exchange rows and columns (MV).
+    /// * `bgr` - Use BGR color order.
+    ///
+    /// The scan direction set with `set_scan_direction` is kept. The ML bit written by
+    /// init is not, so the refresh order is the panel default unless chosen explicitly.
     ///
     /// # Returns
     ///
-    /// A reference to the buffer.
-    pub fn get_buffer(&self) -> &[u8] {
-        self.buffer
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn set_madctl_flags(
+        &mut self,
+        mirror_x: bool,
+        mirror_y: bool,
+        swap_xy: bool,
+        bgr: bool,
+    ) -> Result<(), Error> {
+        let madctl = madctl_from(mirror_x, mirror_y, swap_xy, bgr) | self.scan_direction;
+        self.write_command(Instruction::MadCtl as u8, &[madctl])?;
+        self.madctl = madctl;
+        Ok(())
     }
 
-    /// Clears the frame buffer with the specified color.
+    /// Sets the panel refresh direction for modules mounted upside-down.
+    ///
+    /// This controls the ML and MH bits, which only change the order in which the panel
+    /// refreshes, not how frame memory maps to the image. It is independent of the
+    /// MX/MY/MV bits set by `set_orientation` and `set_madctl_flags`: the scan direction is
+    /// kept when the orientation changes, and the orientation is kept here.
     ///
     /// # Arguments
     ///
-    /// * `color` - The color to clear the buffer with.
-    pub fn clear(&mut self, color: Rgb565) {
-        let raw_color = color.into_storage();
-        for chunk in self.buffer.chunks_exact_mut(2) {
-            chunk[0] = (raw_color >> 8) as u8;
-            chunk[1] = raw_color as u8;
+    /// * `top_to_bottom` - Refresh rows from top to bottom (ML clear).
+    /// * `left_to_right` - Refresh columns from left to right (MH clear).
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn set_scan_direction(
+        &mut self,
+        top_to_bottom: bool,
+        left_to_right: bool,
+    ) -> Result<(), Error> {
+        let mut scan_direction = 0;
+        if !top_to_bottom {
+            scan_direction |= MADCTL_ML;
+        }
+        if !left_to_right {
+            scan_direction |= MADCTL_MH;
         }
+        let madctl = (self.madctl & !(MADCTL_ML | MADCTL_MH)) | scan_direction;
+        self.write_command(Instruction::MadCtl as u8, &[madctl])?;
+        self.madctl = madctl;
+        self.scan_direction = scan_direction;
+        Ok(())
     }
 
-    /// Copies a region from another buffer into this buffer.
+    /// Sets the direction in which column and page addresses advance in frame memory.
+    ///
+    /// This controls the MX and MY bits only, keeping the axis exchange (MV), color order
+    /// and scan direction, so it can reverse an axis that no `Orientation` preset covers,
+    /// such as a mirror-image view for a panel seen through a mirror. `set_orientation`
+    /// sets MX and MY again, so call this after it.
+    ///
+    /// Address windows are still given with their start before their end, in screen
+    /// coordinates: the controller reverses the addresses itself, and each window's pixels
+    /// are then streamed from its far edge, so `show` draws the buffer mirrored. Offsets
+    /// move to the other side of frame memory as for any mirrored axis.
+    /// With MV set, as in the landscape presets, the column address runs vertically on
+    /// screen, so `y_reversed` mirrors the image horizontally.
     ///
     /// # Arguments
     ///
-    /// * `src_buffer` - The source buffer.
-    /// * `src_x` - The x-coordinate of the top-left corner of the source region.
-    /// * `src_y` - The y-coordinate of the top-left corner of the source region.
-    /// * `src_width` - The width of the source region.
-    /// * `src_height` - The height of the source region.
-    /// * `dest_x` - The x-coordinate of the top-left corner of the destination region.
-    /// * `dest_y` - The y-coordinate of the top-left corner of the destination region.
-    pub fn copy_region(
+    /// * `x_reversed` - Advance column addresses from high to low (MX).
+    /// * `y_reversed` - Advance page addresses from high to low (MY).
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn set_address_increment(
         &mut self,
-        src_buffer: &[u8],
-        src_x: u16,
-        src_y: u16,
-        src_width: u32,
-        src_height: u32,
-        dest_x: u16,
-        dest_y: u16,
-    ) {
-        for row in 0..src_height as usize {
-            let src_row_start =
-                (src_y as usize + row) * self.width as usize * 2 + src_x as usize * 2;
-            let src_row_end = src_row_start + src_width as usize * 2;
+        x_reversed: bool,
+        y_reversed: bool,
+    ) -> Result<(), Error> {
+        let mut madctl = self.madctl & !(MADCTL_MX | MADCTL_MY);
+        if x_reversed {
+            madctl |= MADCTL_MX;
+        }
+        if y_reversed {
+            madctl |= MADCTL_MY;
+        }
+        self.write_command(Instruction::MadCtl as u8, &[madctl])?;
+        self.madctl = madctl;
+        Ok(())
+    }
 
-            let dest_row_start =
-                (dest_y as usize + row) * self.width as usize * 2 + dest_x as usize * 2;
-            let dest_row_end = dest_row_start + src_width as usize * 2;
+    /// Turns display inversion on or off.
+    ///
+    /// `init` turns inversion on unless disabled with `set_init_inversion`.
+    ///
+    /// # Arguments
+    ///
+    /// * `inverted` - Whether to invert the displayed colors.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn set_inversion(&mut self, inverted: bool) -> Result<(), Error> {
+        let command = if inverted {
+            Instruction::InvOn
+        } else {
+            Instruction::InvOff
+        };
+        self.write_command(command as u8, &[])?;
+        self.inverted = inverted;
+        Ok(())
+    }
 
-            self.buffer[dest_row_start..dest_row_end]
-                .copy_from_slice(&src_buffer[src_row_start..src_row_end]);
+    /// Captures the offset, orientation and inversion settings.
+    ///
+    /// The orientation is the MADCTL value last set with `set_orientation`,
+    /// `set_madctl_flags`, `set_scan_direction` or `set_address_increment`.
+    ///
+    /// # Returns
+    ///
+    /// The current `DisplayState`.
+    pub fn state_snapshot(&self) -> DisplayState {
+        DisplayState {
+            dx: self.dx,
+            dy: self.dy,
+            madctl: self.madctl,
+            inverted: self.inverted,
         }
     }
 
-    /// Restores regions from a source buffer into the frame buffer.
+    /// Restores settings captured with `state_snapshot`, re-sending the commands they need.
     ///
     /// # Arguments
     ///
-    /// * `src_buffer` - The source buffer.
-    /// * `regions` - An array of regions to restore.
-    pub fn copy_regions(&mut self, src_buffer: &[u8], regions: &[Option<Region>]) {
-        for region in regions.iter().flatten() {
-            self.copy_region(
-                src_buffer,
-                region.x,
-                region.y,
-                region.width,
-                region.height,
-                region.x,
-                region.y,
-            );
-        }
+    /// * `state` - The state to restore.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn apply_state(&mut self, state: &DisplayState) -> Result<(), Error> {
+        self.write_command(Instruction::MadCtl as u8, &[state.madctl])?;
+        self.madctl = state.madctl;
+        self.set_inversion(state.inverted)?;
+        self.set_offset(state.dx, state.dy)
     }
 
-    /// Compares the current frame buffer with another frame buffer and returns an iterator
-    /// of `Pixel` that can be drawn to update the display.
+    /// Selects one of the controller's preset gamma curves with GAMSET (0x26).
+    ///
+    /// This is a quick alternative to uploading full gamma tables with `load_calibration`.
+    /// Curves are numbered by bit position, as in the MIPI command set.
     ///
     /// # Arguments
     ///
-    /// * `other` - The other frame buffer to compare against.
+    /// * `curve` - The gamma curve: 1, 2, 4 or 8.
     ///
     /// # Returns
     ///
-    /// An iterator of `Pixel<Rgb565>`.
-    pub fn diff_with<'b>(&'b self, other: &'b FrameBuffer<'a>) -> impl Iterator<Item = Pixel<Rgb565>> + 'b {
-        self.buffer.chunks_exact(2).enumerate().filter_map(move |(i, chunk)| {
-            let other_chunk = &other.buffer[i * 2..i * 2 + 2];
-            if chunk != other_chunk {
-                let x = (i as u32 % self.width) as i32;
-                let y = (i as u32 / self.width) as i32;
-                let raw_color = u16::from_be_bytes([chunk[0], chunk[1]]);
-                let color = Rgb565::from(RawU16::new(raw_color));
-                Some(Pixel(Point::new(x, y), color))
-            } else {
-                None
-            }
-        })
+    /// `Result<(), Error>` indicating success or failure, with `Error::InvalidArgument` if
+    /// the curve is not one of the allowed values.
+    pub fn select_gamma_curve(&mut self, curve: u8) -> Result<(), Error> {
+        if !matches!(curve, 1 | 2 | 4 | 8) {
+            return Err(Error::InvalidArgument);
+        }
+        self.write_command(Instruction::GamSet as u8, &[curve])
     }
-}
 
-impl<'a> DrawTarget for FrameBuffer<'a> {
-    type Color = Rgb565;
-    type Error = ();
+    /// Applies per-unit calibration values.
+    ///
+    /// This sends the gamma tables to SET_GAMMA1-4 (0xF0-0xF3) and the voltage settings to
+    /// 0xC3, 0xC4 and 0xC9. See `Calibration` for the mapping of each field.
+    ///
+    /// # Arguments
+    ///
+    /// * `cal` - The calibration to apply.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn load_calibration(&mut self, cal: &Calibration) -> Result<(), Error> {
+        self.write_command(Instruction::Gamma1 as u8, &cal.gamma_positive[0])?;
+        self.write_command(Instruction::Gamma2 as u8, &cal.gamma_positive[1])?;
+        self.write_command(Instruction::Gamma3 as u8, &cal.gamma_negative[0])?;
+        self.write_command(Instruction::Gamma4 as u8, &cal.gamma_negative[1])?;
+        self.write_command(Instruction::PwCtr4 as u8, &[cal.vreg1a])?;
+        self.write_command(Instruction::PwCtr5 as u8, &[cal.vreg1b])?;
+        self.write_command(Instruction::Vreg2a as u8, &[cal.vreg2a])?;
+        Ok(())
+    }
 
-    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
-    where
-        I: IntoIterator<Item = Pixel<Self::Color>>,
-    {
-        for Pixel(coord, color) in pixels {
-            if coord.x >= 0
-                && coord.x < self.width as i32
-                && coord.y >= 0
-                && coord.y < self.height as i32
-            {
-                let index = ((coord.y as u32 * self.width + coord.x as u32) * 2) as usize;
-                let raw_color = color.into_storage();
-                self.buffer[index] = (raw_color >> 8) as u8;
-                self.buffer[index + 1] = raw_color as u8;
-            }
+    /// Sets the global offset of the displayed image.
+    ///
+    /// The offset is the position of the visible area in the controller's memory in
+    /// `Portrait` orientation. For other orientations it is remapped automatically, so the
+    /// same logical coordinates address the same visible pixels in every orientation.
+    ///
+    /// # Arguments
+    ///
+    /// * `dx` - Horizontal offset.
+    /// * `dy` - Vertical offset.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success, or `Error::OutOfBounds` if the offset would
+    /// push the last column or row of the display past the 16-bit address range. The
+    /// previous offset is kept in that case.
+    pub fn set_offset(&mut self, dx: u16, dy: u16) -> Result<(), Error> {
+        let fits =
+            |offset: u16, size: u32| offset as u32 + size.saturating_sub(1) <= u16::MAX as u32;
+        if !fits(dx, self.width) || !fits(dy, self.height) {
+            return Err(Error::OutOfBounds);
         }
+
+        self.dx = dx;
+        self.dy = dy;
         Ok(())
     }
-}
 
-impl<'a> OriginDimensions for FrameBuffer<'a> {
-    fn size(&self) -> Size {
+    /// Returns the full drawable area of the display.
+    pub fn visible_bounds(&self) -> Region {
+        Region {
+            x: 0,
+            y: 0,
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Returns the largest centered square that fits inside the round visible area.
+    ///
+    /// The circle's diameter is the smaller of the display's width and height. Content
+    /// placed inside this square is never clipped by the bezel.
+    pub fn inscribed_square(&self) -> Region {
+        let diameter = self.width.min(self.height);
+
+        // Largest side where the square's diagonal still fits in the circle
+        let mut side = diameter;
+        while side * side * 2 > diameter * diameter {
+            side -= 1;
+        }
+
+        Region {
+            x: ((self.width - side) / 2) as u16,
+            y: ((self.height - side) / 2) as u16,
+            width: side,
+            height: side,
+        }
+    }
+
+    /// Enables or disables clipping to the round visible area.
+    ///
+    /// When enabled, `write_pixel` (and therefore the `DrawTarget` implementation) skips
+    /// pixels outside the circle inscribed in the display. The visible span of each row is
+    /// computed once here, so the per-pixel check is two comparisons.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to clip to the circle.
+    pub fn set_circular_clip(&mut self, enabled: bool) {
+        if !enabled {
+            self.circle_spans = None;
+            return;
+        }
+
+        // Work in doubled coordinates so pixel centers and the circle center are integers
+        let width = self.width as i32;
+        let height = self.height as i32;
+        let diameter = width.min(height);
+        let mut spans = [(1, 0); RAM_SIZE as usize];
+
+        for (y, span) in spans.iter_mut().enumerate().take(height as usize) {
+            let dy = 2 * y as i32 + 1 - height;
+            if let Some(x_min) = (0..(width + 1) / 2)
+                .find(|&x| (2 * x + 1 - width).pow(2) + dy * dy <= diameter * diameter)
+            {
+                *span = (x_min as u16, (width - 1 - x_min) as u16);
+            }
+        }
+
+        self.circle_spans = Some(spans);
+    }
+
+    /// Returns whether a pixel lies outside the circular clip area.
+    fn is_clipped(&self, x: u16, y: u16) -> bool {
+        match &self.circle_spans {
+            Some(spans) => match spans.get(y as usize) {
+                Some(&(x_min, x_max)) => x < x_min || x > x_max,
+                None => true,
+            },
+            None => false,
+        }
+    }
+
+    /// Sets the address window for the display.
+    ///
+    /// This function sets the address window for subsequent drawing commands.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_x` - Start x-coordinate.
+    /// * `start_y` - Start y-coordinate.
+    /// * `end_x` - End x-coordinate.
+    /// * `end_y` - End y-coordinate.
+    ///
+    /// An end before its start describes an empty window, and nothing is sent.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::OutOfBounds` if
+    /// adding the offset overflows a 16-bit address.
+    pub fn set_address_window(
+        &mut self,
+        start_x: u16,
+        start_y: u16,
+        end_x: u16,
+        end_y: u16,
+    ) -> Result<(), Error> {
+        if end_x < start_x || end_y < start_y {
+            return Ok(());
+        }
+
+        let (dx, dy) = self.window_offset();
+        let add = |value: u16, offset: u16| value.checked_add(offset).ok_or(Error::OutOfBounds);
+        self.set_raw_window(
+            add(start_x, dx)?,
+            add(start_y, dy)?,
+            add(end_x, dx)?,
+            add(end_y, dy)?,
+        )?;
+
+        // A window skipped by RamWr continuation keeps its cursor where the last write left it
+        if self.mirror_cursor.is_none() {
+            let region = Region {
+                x: start_x,
+                y: start_y,
+                width: (end_x - start_x) as u32 + 1,
+                height: (end_y - start_y) as u32 + 1,
+            };
+            self.mirror_cursor = Some((region, 0));
+        }
+        Ok(())
+    }
+
+    /// Sets the address window to cover a region.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to address.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success, or `Error::OutOfBounds` if the region's end
+    /// does not fit in `u16` coordinates. An empty region is a no-op.
+    pub fn set_address_window_region(&mut self, region: &Region) -> Result<(), Error> {
+        if region.is_empty() {
+            return Ok(());
+        }
+
+        let end_x = Self::region_end(region.x, region.width)?;
+        let end_y = Self::region_end(region.y, region.height)?;
+        self.set_address_window(region.x, region.y, end_x, end_y)
+    }
+
+    /// Sets the address window in raw controller coordinates.
+    ///
+    /// Unlike `set_address_window`, the values are written to CaSet/RaSet exactly as given,
+    /// without applying the global offset or orientation remapping.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_x` - Start column.
+    /// * `start_y` - Start row.
+    /// * `end_x` - End column.
+    /// * `end_y` - End row.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn set_raw_window(
+        &mut self,
+        start_x: u16,
+        start_y: u16,
+        end_x: u16,
+        end_y: u16,
+    ) -> Result<(), Error> {
+        let window = [start_x, start_y, end_x, end_y];
+        if self.ram_write_continuation && self.ram_write_open && self.window == Some(window) {
+            return Ok(());
+        }
+
+        self.write_command(Instruction::CaSet as u8, &[])?;
+        self.start_data()?;
+        self.write_word(start_x)?;
+        self.write_word(end_x)?;
+        self.write_command(Instruction::RaSet as u8, &[])?;
+        self.start_data()?;
+        self.write_word(start_y)?;
+        self.write_word(end_y)?;
+        self.window = Some(window);
+        Ok(())
+    }
+
+    /// Enables or disables RamWr continuation.
+    ///
+    /// When enabled, an operation that writes to the same window as the previous one, with
+    /// no other command in between, skips the CaSet, RaSet and RamWr commands and streams
+    /// its pixels straight away. The controller wraps its write pointer back to the start
+    /// of the window once the window is full, so this is only correct when every operation
+    /// on the window writes it completely, as repeated `show_region` calls for an
+    /// animation do. Any other command, including one that sets a different window, ends
+    /// the continuation.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to skip redundant window and RamWr commands.
+    pub fn set_ram_write_continuation(&mut self, enabled: bool) {
+        self.ram_write_continuation = enabled;
+        self.ram_write_open = false;
+    }
+
+    /// Returns the column and row offsets for the current scan configuration.
+    ///
+    /// Mirroring an axis (MX/MY) moves the visible area's margin to the other side of the
+    /// controller's memory, and exchanging axes (MV) swaps the column and row offsets.
+    fn window_offset(&self) -> (u16, u16) {
+        let mut offset_x = self.dx;
+        let mut offset_y = self.dy;
+        if self.madctl & MADCTL_MX != 0 {
+            offset_x = RAM_SIZE.saturating_sub((self.width as u16).saturating_add(self.dx));
+        }
+        if self.madctl & MADCTL_MY != 0 {
+            offset_y = RAM_SIZE.saturating_sub((self.height as u16).saturating_add(self.dy));
+        }
+        if self.madctl & MADCTL_MV != 0 {
+            core::mem::swap(&mut offset_x, &mut offset_y);
+        }
+
+        (offset_x, offset_y)
+    }
+
+    /// Clears the screen by filling it with a single color.
+    ///
+    /// This function sets the entire display to the specified color by writing data
+    /// in chunks, which balances memory efficiency and performance.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to fill the screen with, in RGB565 format.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn clear_screen(&mut self, color: u16) -> Result<(), Error> {
+        self.fill_rect(0, 0, self.width, self.height, color)
+    }
+
+    /// Fills a rectangle on the display with a single color.
+    ///
+    /// This function sets the address window to the rectangle and streams the color in
+    /// chunks, without needing a source buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate of the top-left corner.
+    /// * `y` - Y-coordinate of the top-left corner.
+    /// * `width` - Width of the rectangle.
+    /// * `height` - Height of the rectangle.
+    /// * `color` - The color to fill the rectangle with, in RGB565 format.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::OutOfBounds` if the
+    /// rectangle extends past the 16-bit address range.
+    pub fn fill_rect(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u32,
+        height: u32,
+        color: u16,
+    ) -> Result<(), Error> {
+        self.ensure_initialized()?;
+
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+
+        // Set the address window to cover the rectangle
+        let end_x = Self::region_end(x, width)?;
+        let end_y = Self::region_end(y, height)?;
+        let total_pixels = (width as usize)
+            .checked_mul(height as usize)
+            .ok_or(Error::OutOfBounds)?;
+        self.set_address_window(x, y, end_x, end_y)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        // Define a constant for the chunk size
+        const CHUNK_SIZE: usize = 512;
+        let mut chunk = [0u8; CHUNK_SIZE * 2];
+
+        // Fill the chunk with the color data
+        color::fill_chunk_rgb565(&mut chunk, color);
+
+        // Write data in chunks
+        let full_chunks = total_pixels / CHUNK_SIZE;
+        let remaining_pixels = total_pixels % CHUNK_SIZE;
+
+        for i in 0..full_chunks {
+            self.write_ram_chunk(&chunk, i == 0)?;
+        }
+
+        if remaining_pixels > 0 {
+            self.write_ram_chunk(&chunk[0..(remaining_pixels * 2)], full_chunks == 0)?;
+        }
+
+        Ok(())
+    }
+
+    /// Fills a region of the display with a single color.
+    ///
+    /// This is a quick way to erase an area when the background is a solid color.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to fill.
+    /// * `color` - The color to fill the region with, in RGB565 format.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn clear_region(&mut self, region: Region, color: u16) -> Result<(), Error> {
+        self.fill_rect(region.x, region.y, region.width, region.height, color)
+    }
+
+    /// Writes a horizontal run of identical pixels.
+    ///
+    /// This is the building block for spans in custom rasterizers: a one-row window is set
+    /// and the color streamed `len` times.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate of the first pixel.
+    /// * `y` - Y-coordinate of the row.
+    /// * `len` - Number of pixels in the run.
+    /// * `color` - Color of the run, in RGB565 format.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>`, with `Error::OutOfBounds` if the run does not fit on the row.
+    pub fn write_run(&mut self, x: u16, y: u16, len: u32, color: u16) -> Result<(), Error> {
+        if (x as u32).saturating_add(len) > self.width || y as u32 >= self.height {
+            return Err(Error::OutOfBounds);
+        }
+        if len == 0 {
+            return Ok(());
+        }
+
+        self.fill_rect(x, y, len, 1, color)
+    }
+
+    /// Reads the color of a pixel back from the display memory.
+    ///
+    /// The controller returns pixels in 18-bit format, which is converted to RGB565. This
+    /// requires an SPI bus with a working MISO line.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate.
+    /// * `y` - Y-coordinate.
+    ///
+    /// # Returns
+    ///
+    /// The pixel color in RGB565 format, or an error.
+    pub fn read_pixel(&mut self, x: u16, y: u16) -> Result<u16, Error> {
+        self.ensure_initialized()?;
+
+        self.set_address_window(x, y, x, y)?;
+        // One dummy byte followed by one byte per channel
+        let mut response = [0u8; 4];
+        self.read_command(Instruction::RamRd as u8, &mut response)?;
+
+        let red = (response[1] >> 3) as u16;
+        let green = (response[2] >> 2) as u16;
+        let blue = (response[3] >> 3) as u16;
+        Ok((red << 11) | (green << 5) | blue)
+    }
+
+    /// Inverts the colors of a region directly on the panel.
+    ///
+    /// Each row is read back, inverted and written again, so no frame buffer is needed.
+    /// Calling it twice restores the original pixels, which makes it suitable for press
+    /// feedback. This requires an SPI bus with a working MISO line.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to invert.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success, or `Error::OutOfBounds` if the region does
+    /// not fit on the display.
+    pub fn invert_region_on_panel(&mut self, region: Region) -> Result<(), Error> {
+        self.ensure_initialized()?;
+
+        if region.x as u32 + region.width > self.width.min(RAM_SIZE as u32)
+            || region.y as u32 + region.height > self.height
+        {
+            return Err(Error::OutOfBounds);
+        }
+        if region.is_empty() {
+            return Ok(());
+        }
+
+        let end_x = region.x + region.width as u16 - 1;
+        // One dummy byte followed by three bytes per pixel
+        let mut response = [0u8; 1 + RAM_SIZE as usize * 3];
+        let mut row = [0u8; RAM_SIZE as usize * 2];
+        let pixels = region.width as usize;
+
+        for y in region.y..region.y + region.height as u16 {
+            self.set_address_window(region.x, y, end_x, y)?;
+            self.read_command(Instruction::RamRd as u8, &mut response[..1 + pixels * 3])?;
+
+            for (i, rgb) in response[1..1 + pixels * 3].chunks_exact(3).enumerate() {
+                let color = ((rgb[0] as u16 >> 3) << 11)
+                    | ((rgb[1] as u16 >> 2) << 5)
+                    | (rgb[2] as u16 >> 3);
+                row[i * 2..i * 2 + 2].copy_from_slice(&(!color).to_be_bytes());
+            }
+
+            self.write_command(Instruction::RamWr as u8, &[])?;
+            self.start_data()?;
+            self.write_data(&row[..pixels * 2])?;
+        }
+
+        Ok(())
+    }
+
+    /// Briefly fills a region with a highlight color, then restores it from a buffer.
+    ///
+    /// This gives press feedback on buses without readback.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to flash.
+    /// * `color` - The highlight color, in RGB565 format.
+    /// * `background` - Full-screen buffer the region is restored from.
+    /// * `delay` - Delay provider.
+    /// * `ms` - How long the highlight stays on screen, in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn flash_region<DELAY>(
+        &mut self,
+        region: Region,
+        color: u16,
+        background: &[u8],
+        delay: &mut DELAY,
+        ms: u32,
+    ) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        self.clear_region(region, color)?;
+        delay.delay_ms(ms);
+        self.show_region(background, region.x, region.y, region.width, region.height)
+    }
+
+    /// Checks that the panel reports being awake and switched on.
+    ///
+    /// This reads the display status (RDDST, 0x09) and tests the sleep out and display on
+    /// bits, catching the case where the init sequence was sent but not latched. This
+    /// requires an SPI bus with a working MISO line.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the panel is out of sleep and the display is on, `false` otherwise, or
+    /// an error.
+    pub fn check_ready(&mut self) -> Result<bool, Error> {
+        // One dummy byte followed by the four status bytes
+        let mut response = [0u8; 5];
+        self.read_command(Instruction::RddSt as u8, &mut response)?;
+
+        let sleep_out = response[2] & 0x02 != 0;
+        let display_on = response[3] & 0x04 != 0;
+        Ok(sleep_out && display_on)
+    }
+
+    /// Writes a pixel and verifies it by reading it back.
+    ///
+    /// Intended for a few critical pixels, such as warning indicators, where a corrupted
+    /// transfer must be detected. This requires an SPI bus with a working MISO line.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate.
+    /// * `y` - Y-coordinate.
+    /// * `color` - Color of the pixel, in RGB565 format.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the value read back matches `color`, `false` otherwise, or an error, with
+    /// `Error::OutOfBounds` if circular clipping is enabled and the pixel lies outside the
+    /// circle.
+    pub fn write_pixel_verified(&mut self, x: u16, y: u16, color: u16) -> Result<bool, Error> {
+        self.ensure_initialized()?;
+        if self.is_clipped(x, y) {
+            return Err(Error::OutOfBounds);
+        }
+
+        self.write_pixel(x, y, color)?;
+        Ok(self.read_pixel(x, y)? == color)
+    }
+
+    /// Probes the color order by writing a pure red pixel and reading it back.
+    ///
+    /// The pixel at (0, 0) is set to red, read back and then restored. If the red value
+    /// comes back in the first channel the display is reported as RGB. The pixel is
+    /// written even when circular clipping is enabled, as it lies outside the circle. This
+    /// requires an SPI bus with a working MISO line.
+    ///
+    /// # Returns
+    ///
+    /// `true` for RGB order, `false` for BGR, or an error.
+    pub fn probe_color_order(&mut self) -> Result<bool, Error> {
+        let original = self.read_pixel(0, 0)?;
+
+        let red = Rgb565::RED.into_storage();
+        self.write_pixel_unclipped(0, 0, red)?;
+        let probed = self.read_pixel(0, 0)?;
+        self.write_pixel_unclipped(0, 0, original)?;
+
+        Ok((probed >> 11) > (probed & 0x1F))
+    }
+
+    /// Draws a test pattern directly to the display.
+    ///
+    /// The pattern is streamed through address windows without a frame buffer, which makes
+    /// it useful to check pixel mapping, color order and orientation during bring-up.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The test pattern to draw.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn draw_test_pattern(&mut self, pattern: TestPattern) -> Result<(), Error> {
+        let width = self.width;
+        let height = self.height;
+
+        match pattern {
+            TestPattern::ColorBars => {
+                let colors = [
+                    Rgb565::WHITE,
+                    Rgb565::YELLOW,
+                    Rgb565::CYAN,
+                    Rgb565::GREEN,
+                    Rgb565::MAGENTA,
+                    Rgb565::RED,
+                    Rgb565::BLUE,
+                    Rgb565::BLACK,
+                ];
+                let count = colors.len() as u32;
+                for (i, color) in colors.iter().enumerate() {
+                    let start = width * i as u32 / count;
+                    let end = width * (i as u32 + 1) / count;
+                    self.fill_rect(start as u16, 0, end - start, height, color.into_storage())?;
+                }
+            }
+            TestPattern::Crosshatch => {
+                const SPACING: u32 = 20;
+                self.clear_screen(Rgb565::BLACK.into_storage())?;
+                for x in (0..width).step_by(SPACING as usize) {
+                    self.fill_rect(x as u16, 0, 1, height, Rgb565::WHITE.into_storage())?;
+                }
+                for y in (0..height).step_by(SPACING as usize) {
+                    self.fill_rect(0, y as u16, width, 1, Rgb565::WHITE.into_storage())?;
+                }
+            }
+            TestPattern::Gradient => {
+                self.ensure_initialized()?;
+                self.set_address_window(0, 0, width as u16 - 1, height as u16 - 1)?;
+                self.write_command(Instruction::RamWr as u8, &[])?;
+                self.start_data()?;
+
+                const CHUNK_SIZE: usize = 512;
+                let mut chunk = [0u8; CHUNK_SIZE];
+                let mut len = 0;
+                for y in 0..height {
+                    for x in 0..width {
+                        let red = (x * 31 / (width - 1).max(1)) as u8;
+                        let green = (y * 63 / (height - 1).max(1)) as u8;
+                        let color = Rgb565::new(red, green, 31 - red).into_storage();
+                        chunk[len..len + 2].copy_from_slice(&color.to_be_bytes());
+                        len += 2;
+                        if len == CHUNK_SIZE {
+                            self.write_data(&chunk)?;
+                            len = 0;
+                        }
+                    }
+                }
+                if len > 0 {
+                    self.write_data(&chunk[..len])?;
+                }
+            }
+            TestPattern::CornerMarkers => {
+                let half_w = width / 2;
+                let half_h = height / 2;
+                let quadrants = [
+                    (0, 0, half_w, half_h, Rgb565::RED),
+                    (half_w, 0, width - half_w, half_h, Rgb565::GREEN),
+                    (0, half_h, half_w, height - half_h, Rgb565::BLUE),
+                    (
+                        half_w,
+                        half_h,
+                        width - half_w,
+                        height - half_h,
+                        Rgb565::WHITE,
+                    ),
+                ];
+                for (x, y, w, h, color) in quadrants {
+                    self.fill_rect(x as u16, y as u16, w, h, color.into_storage())?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sets a pixel color at the given coordinates.
+    ///
+    /// This function sets the color of a single pixel at the specified coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate.
+    /// * `y` - Y-coordinate.
+    /// * `color` - Color of the pixel.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn write_pixel(&mut self, x: u16, y: u16, color: u16) -> Result<(), Error> {
+        self.ensure_initialized()?;
+
+        if self.is_clipped(x, y) {
+            return Ok(());
+        }
+
+        self.write_pixel_unclipped(x, y, color)
+    }
+
+    /// Sets a pixel color, ignoring the circular clip.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate.
+    /// * `y` - Y-coordinate.
+    /// * `color` - Color of the pixel.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_pixel_unclipped(&mut self, x: u16, y: u16, color: u16) -> Result<(), Error> {
+        self.set_address_window(x, y, x, y)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+        self.write_word(color)
+    }
+
+    /// Sets a pixel color at a validated point.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - Point created with `ScreenPoint::new` for this display.
+    /// * `color` - Color of the pixel.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn write_pixel_at(&mut self, point: ScreenPoint, color: u16) -> Result<(), Error> {
+        self.write_pixel(point.x, point.y, color)
+    }
+
+    /// Draws an image from a slice of RGB565 data.
+    ///
+    /// This function draws an image from a slice of pixel data in RGB565 format.
+    /// The image must cover the whole display; use `draw_image_sized` for smaller images.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_data` - Image data to draw, `width * height * 2` bytes for the display.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::BufferSize` if the
+    /// image data does not match the display size.
+    pub fn draw_image(&mut self, image_data: &[u8]) -> Result<(), Error> {
+        self.draw_image_sized(image_data, 0, 0, self.width, self.height)
+    }
+
+    /// Restores the whole panel to a background image, such as one embedded in flash.
+    ///
+    /// This resets the screen to a known background in one pass without keeping a copy of
+    /// the background in RAM, for example before redrawing an animation's moving parts.
+    /// The image is streamed in large chunks regardless of `set_chunk_size`.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - Full-screen background in RGB565 format, `width * height * 2` bytes.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::BufferSize` if the
+    /// image does not match the display size.
+    pub fn reset_to_image(&mut self, image: &[u8]) -> Result<(), Error> {
+        self.with_bulk_transfer(|display| display.draw_image(image))
+    }
+
+    /// Draws an image of a given size at a position.
+    ///
+    /// The address window is set to the image rectangle and the data streamed using the
+    /// configured chunk size (see `set_chunk_size`).
+    ///
+    /// # Arguments
+    ///
+    /// * `image_data` - Image data in RGB565 format, exactly `width * height * 2` bytes.
+    /// * `x` - X-coordinate of the image's top-left corner.
+    /// * `y` - Y-coordinate of the image's top-left corner.
+    /// * `width` - Width of the image.
+    /// * `height` - Height of the image.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::BufferSize` if the
+    /// image data length does not match the dimensions, or `Error::OutOfBounds` if the
+    /// image does not fit on the display.
+    pub fn draw_image_sized(
+        &mut self,
+        image_data: &[u8],
+        x: u16,
+        y: u16,
+        width: u32,
+        height: u32,
+    ) -> Result<(), Error> {
+        self.ensure_initialized()?;
+
+        if image_data.len() as u64 != width as u64 * height as u64 * 2 {
+            return Err(Error::BufferSize);
+        }
+        if x as u32 + width > self.width || y as u32 + height > self.height {
+            return Err(Error::OutOfBounds);
+        }
+
+        let region = Region {
+            x,
+            y,
+            width,
+            height,
+        };
+        if region.is_empty() {
+            return Ok(());
+        }
+
+        self.set_address_window_region(&region)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        for (i, chunk) in image_data.chunks(self.chunk_size).enumerate() {
+            self.write_ram_chunk(chunk, i == 0)?;
+        }
+
+        Ok(())
+    }
+
+    /// Draws a run-length encoded image into a region.
+    ///
+    /// The data is a sequence of 3-byte runs: a count from 1 to 255, then the RGB565 color
+    /// of the run, high byte first. Runs fill the region row by row and may continue from
+    /// one row to the next. Images with large flat areas shrink to a small fraction of
+    /// their raw size. Runs are expanded into a 512-byte buffer while they are streamed,
+    /// so no frame buffer is needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - Where the image is drawn.
+    /// * `rle` - The encoded image.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::OutOfBounds` if the
+    /// region does not fit on the display, or `Error::BufferSize` if the data is not a
+    /// whole number of runs, has a zero count, or does not cover exactly the region's
+    /// pixels. Nothing is sent when the data is invalid.
+    pub fn draw_image_rle(&mut self, region: &Region, rle: &[u8]) -> Result<(), Error> {
+        self.ensure_initialized()?;
+
+        if region.x as u32 + region.width > self.width
+            || region.y as u32 + region.height > self.height
+        {
+            return Err(Error::OutOfBounds);
+        }
+
+        let mut total = 0u64;
+        for run in rle.chunks(3) {
+            if run.len() != 3 || run[0] == 0 {
+                return Err(Error::BufferSize);
+            }
+            total += run[0] as u64;
+        }
+        if total != region.width as u64 * region.height as u64 {
+            return Err(Error::BufferSize);
+        }
+        if region.is_empty() {
+            return Ok(());
+        }
+
+        self.set_address_window_region(region)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        const CHUNK_SIZE: usize = 512;
+        let mut chunk = [0u8; CHUNK_SIZE];
+        let mut len = 0;
+        for run in rle.chunks_exact(3) {
+            for _ in 0..run[0] {
+                chunk[len..len + 2].copy_from_slice(&run[1..3]);
+                len += 2;
+                if len == CHUNK_SIZE {
+                    self.write_data(&chunk)?;
+                    len = 0;
+                }
+            }
+        }
+        if len > 0 {
+            self.write_data(&chunk[..len])?;
+        }
+
+        Ok(())
+    }
+
+    /// Draws a QOI image at the top-left corner of the display.
+    ///
+    /// See `draw_qoi_at`.
+    ///
+    /// # Arguments
+    ///
+    /// * `qoi_data` - The encoded image, including its header.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    #[cfg(feature = "qoi")]
+    pub fn draw_qoi(&mut self, qoi_data: &[u8]) -> Result<(), Error> {
+        self.draw_qoi_at(qoi_data, 0, 0)
+    }
+
+    /// Draws a QOI image, decoding it while it is streamed to the display.
+    ///
+    /// QOI images typically take about half the flash of raw RGB565 data. Pixels are
+    /// decoded into a 512-byte buffer that is sent whenever it fills, so the image is never
+    /// held in memory as a whole. Alpha is ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `qoi_data` - The encoded image, including its header.
+    /// * `x` - X-coordinate of the image's top-left corner.
+    /// * `y` - Y-coordinate of the image's top-left corner.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::InvalidArgument` if
+    /// the data has no valid QOI header, `Error::OutOfBounds` if the image does not fit on
+    /// the display at that position, or `Error::BufferSize` if the data ends before the
+    /// last pixel. In the last case the pixels decoded so far have already been sent.
+    #[cfg(feature = "qoi")]
+    pub fn draw_qoi_at(&mut self, qoi_data: &[u8], x: u16, y: u16) -> Result<(), Error> {
+        self.ensure_initialized()?;
+
+        let mut decoder = qoi::QoiDecoder::new(qoi_data).ok_or(Error::InvalidArgument)?;
+        let region = Region {
+            x,
+            y,
+            width: decoder.width(),
+            height: decoder.height(),
+        };
+        if x as u32 + region.width > self.width || y as u32 + region.height > self.height {
+            return Err(Error::OutOfBounds);
+        }
+        if region.is_empty() {
+            return Ok(());
+        }
+
+        self.set_address_window_region(&region)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        const CHUNK_SIZE: usize = 512;
+        let mut chunk = [0u8; CHUNK_SIZE];
+        let mut len = 0;
+        for _ in 0..region.width * region.height {
+            let pixel = decoder.next_rgb565().ok_or(Error::BufferSize)?;
+            chunk[len..len + 2].copy_from_slice(&color::pack_rgb565_be(pixel));
+            len += 2;
+            if len == CHUNK_SIZE {
+                self.write_data(&chunk)?;
+                len = 0;
+            }
+        }
+        if len > 0 {
+            self.write_data(&chunk[..len])?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends a frame buffer smaller than the screen, such as a widget area, to a position
+    /// on the display.
+    ///
+    /// # Arguments
+    ///
+    /// * `fb` - The frame buffer to send.
+    /// * `at` - Where the frame buffer's top-left corner goes on the display.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::OutOfBounds` if the
+    /// frame buffer does not fit on the display at that position, or `Error::BufferSize` if
+    /// its backing buffer is smaller than its dimensions.
+    pub fn show_framebuffer_at(&mut self, fb: &FrameBuffer, at: Point) -> Result<(), Error> {
+        if at.x < 0 || at.y < 0 || at.x > u16::MAX as i32 || at.y > u16::MAX as i32 {
+            return Err(Error::OutOfBounds);
+        }
+
+        let size = fb.size();
+        let len = (size.width * size.height) as usize * BYTES_PER_PIXEL;
+        let data = fb.get_buffer().get(..len).ok_or(Error::BufferSize)?;
+        self.draw_image_sized(data, at.x as u16, at.y as u16, size.width, size.height)
+    }
+
+    /// Draws an image centered on the display.
+    ///
+    /// An image larger than the display is cropped equally on both sides, so its center
+    /// stays in the middle of the panel.
+    ///
+    /// # Arguments
+    ///
+    /// * `image_data` - Image data in RGB565 format, `img_w * img_h * 2` bytes.
+    /// * `img_w` - Width of the image.
+    /// * `img_h` - Height of the image.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::BufferSize` if the
+    /// image data is too short for its dimensions.
+    pub fn draw_image_centered(
+        &mut self,
+        image_data: &[u8],
+        img_w: u32,
+        img_h: u32,
+    ) -> Result<(), Error> {
+        self.ensure_initialized()?;
+
+        if (image_data.len() as u64) < img_w as u64 * img_h as u64 * 2 {
+            return Err(Error::BufferSize);
+        }
+        if img_w == 0 || img_h == 0 {
+            return Ok(());
+        }
+
+        // Offsets into the image when it is cropped, and onto the screen when it is not
+        let (src_x, dest_x, width) = if img_w > self.width {
+            ((img_w - self.width) / 2, 0, self.width)
+        } else {
+            (0, (self.width - img_w) / 2, img_w)
+        };
+        let (src_y, dest_y, height) = if img_h > self.height {
+            ((img_h - self.height) / 2, 0, self.height)
+        } else {
+            (0, (self.height - img_h) / 2, img_h)
+        };
+
+        self.set_address_window(
+            dest_x as u16,
+            dest_y as u16,
+            (dest_x + width - 1) as u16,
+            (dest_y + height - 1) as u16,
+        )?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        for row in src_y..src_y + height {
+            let start = ((row * img_w + src_x) * 2) as usize;
+            let end = start + (width * 2) as usize;
+            for chunk in image_data[start..end].chunks(self.chunk_size) {
+                self.write_data(chunk)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Displays the provided buffer on the screen.
+    ///
+    /// This function writes the entire buffer to the display, assuming the buffer
+    /// contains pixel data for the full display area.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Buffer to display.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn show(&mut self, buffer: &[u8]) -> Result<(), Error> {
+        self.ensure_initialized()?;
+        #[cfg(feature = "metrics")]
+        let started = self.clock.map(|clock| clock());
+
+        self.write_command(Instruction::CaSet as u8, &[])?;
+        self.write_data(&[0x00, 0x00, 0x00, 0xEF])?;
+
+        self.write_command(Instruction::RaSet as u8, &[])?;
+        self.write_data(&[0x00, 0x00, 0x00, 0xEF])?;
+        let screen = Region {
+            x: 0,
+            y: 0,
+            width: self.width,
+            height: self.height,
+        };
+        self.mirror_cursor = Some((screen, 0));
+
+        self.write_command(Instruction::RamWr as u8, &[])?;
+
+        if self.software_brightness < 255 {
+            self.write_dimmed(buffer, true)?;
+        } else if self.write_continue {
+            for (i, chunk) in buffer.chunks(BULK_CHUNK_SIZE).enumerate() {
+                self.write_ram_chunk(chunk, i == 0)?;
+            }
+        } else {
+            self.cs.set_high().map_err(|_| Error::Pin)?;
+            self.dc.set_high().map_err(|_| Error::Pin)?;
+            self.cs.set_low().map_err(|_| Error::Pin)?;
+            self.spi_write(buffer)?;
+            self.cs.set_high().map_err(|_| Error::Pin)?;
+            self.mirror_stream(buffer);
+        }
+
+        #[cfg(feature = "metrics")]
+        self.record_timing(started);
+
+        Ok(())
+    }
+
+    /// Displays a full-screen buffer in two passes, even rows first and then odd rows.
+    ///
+    /// Each row is sent with its own one-row address window through `show_region`, so
+    /// software brightness and the mirror apply as usual. With metrics enabled, each row is
+    /// recorded as a separate transfer.
+    ///
+    /// The GC9A01A refreshes the glass top to bottom from frame memory independently of
+    /// the bus, so interlacing cannot remove tearing: it turns the single boundary between
+    /// old and new content of a progressive `show` into a comb of alternating rows, which
+    /// can hide the tear of a fast-moving object but also shows it half-drawn for one
+    /// refresh. Addressing every row separately costs 11 extra bytes per row, about 2.3%
+    /// more data than `show` on a 240x240 display (117840 bytes instead of 115211,
+    /// measured with the `record` feature). Use a TE pin where one is available.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Buffer to display, covering the full display.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success, or `Error::BufferSize` if the buffer does
+    /// not cover the full display.
+    pub fn show_interlaced(&mut self, buffer: &[u8]) -> Result<(), Error> {
+        if buffer.len() < self.width as usize * self.height as usize * BYTES_PER_PIXEL {
+            return Err(Error::BufferSize);
+        }
+
+        let height = self.height as u16;
+        for y in (0..height).step_by(2).chain((1..height).step_by(2)) {
+            self.show_region(buffer, 0, y, self.width, 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends only the pixels that differ between two full-screen frame buffers.
+    ///
+    /// Each row is scanned for runs of changed pixels, and each run is sent with its own
+    /// one-row address window. Runs separated by up to 8 unchanged pixels are sent as one.
+    /// When more than half of the pixels changed, the whole frame is sent with `show`
+    /// instead, which is faster at that point.
+    ///
+    /// # Arguments
+    ///
+    /// * `new` - The frame to display.
+    /// * `old` - The frame currently on the display.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::BufferSize` if the
+    /// frame buffers do not match the display's size.
+    pub fn show_diff(&mut self, new: &FrameBuffer, old: &FrameBuffer) -> Result<(), Error> {
+        self.ensure_initialized()?;
+        if new.size() != self.size() || old.size() != self.size() {
+            return Err(Error::BufferSize);
+        }
+
+        let width = self.width as usize;
+        let stride = width * BYTES_PER_PIXEL;
+        let len = stride * self.height as usize;
+        let new_buf = new.get_buffer().get(..len).ok_or(Error::BufferSize)?;
+        let old_buf = old.get_buffer().get(..len).ok_or(Error::BufferSize)?;
+
+        let changed = new_buf
+            .chunks_exact(2)
+            .zip(old_buf.chunks_exact(2))
+            .filter(|(a, b)| a != b)
+            .count();
+        if changed == 0 {
+            return Ok(());
+        }
+        if changed * 100 > (len / BYTES_PER_PIXEL) * DIFF_FULL_SHOW_PERCENT {
+            return self.show(new_buf);
+        }
+
+        for y in 0..self.height as usize {
+            let new_row = &new_buf[y * stride..(y + 1) * stride];
+            let old_row = &old_buf[y * stride..(y + 1) * stride];
+            let differs = |x: usize| new_row[x * 2..x * 2 + 2] != old_row[x * 2..x * 2 + 2];
+
+            let mut x = 0;
+            while x < width {
+                if !differs(x) {
+                    x += 1;
+                    continue;
+                }
+
+                // Extend the run over short gaps of unchanged pixels
+                let start = x;
+                let mut end = x;
+                x += 1;
+                while x < width && x - end <= DIFF_MERGE_GAP {
+                    if differs(x) {
+                        end = x;
+                    }
+                    x += 1;
+                }
+
+                self.set_address_window(start as u16, y as u16, end as u16, y as u16)?;
+                self.write_command(Instruction::RamWr as u8, &[])?;
+                self.start_data()?;
+                let run = &new_row[start * 2..(end + 1) * 2];
+                if self.software_brightness < 255 {
+                    self.write_dimmed(run, true)?;
+                } else {
+                    for (i, chunk) in run.chunks(self.chunk_size).enumerate() {
+                        self.write_ram_chunk(chunk, i == 0)?;
+                    }
+                }
+                x = end + 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Slides a tile onto the display from one of its edges.
+    ///
+    /// The tile ends flush with the edge, centered along it, like a notification sliding
+    /// in from the top. Each step sends the part of the tile that has come into view, which
+    /// covers everything sent in the step before, so nothing needs to be erased.
+    ///
+    /// # Arguments
+    ///
+    /// * `tile` - The rendered tile, no larger than the display.
+    /// * `from` - The edge the tile slides in from.
+    /// * `steps` - Number of frames the slide takes. Zero is treated as one.
+    /// * `delay` - Delay provider.
+    /// * `step_ms` - Delay between frames in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::OutOfBounds` if the
+    /// tile is larger than the display.
+    pub fn slide_in<DELAY>(
+        &mut self,
+        tile: &FrameBuffer,
+        from: Edge,
+        steps: u8,
+        delay: &mut DELAY,
+        step_ms: u32,
+    ) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        self.ensure_initialized()?;
+
+        let Size { width, height } = tile.size();
+        if width > self.width || height > self.height {
+            return Err(Error::OutOfBounds);
+        }
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        let len = (width * height) as usize * BYTES_PER_PIXEL;
+        let data = tile.get_buffer().get(..len).ok_or(Error::BufferSize)?;
+
+        let center_x = ((self.width - width) / 2) as u16;
+        let center_y = ((self.height - height) / 2) as u16;
+        let steps = steps.max(1) as u32;
+
+        for step in 1..=steps {
+            match from {
+                Edge::Top => {
+                    let shown = height * step / steps;
+                    let part = Region {
+                        x: 0,
+                        y: (height - shown) as u16,
+                        width,
+                        height: shown,
+                    };
+                    self.send_tile_part(data, width, &part, center_x, 0)?;
+                }
+                Edge::Bottom => {
+                    let shown = height * step / steps;
+                    let part = Region {
+                        x: 0,
+                        y: 0,
+                        width,
+                        height: shown,
+                    };
+                    let y = (self.height - shown) as u16;
+                    self.send_tile_part(data, width, &part, center_x, y)?;
+                }
+                Edge::Left => {
+                    let shown = width * step / steps;
+                    let part = Region {
+                        x: (width - shown) as u16,
+                        y: 0,
+                        width: shown,
+                        height,
+                    };
+                    self.send_tile_part(data, width, &part, 0, center_y)?;
+                }
+                Edge::Right => {
+                    let shown = width * step / steps;
+                    let part = Region {
+                        x: 0,
+                        y: 0,
+                        width: shown,
+                        height,
+                    };
+                    let x = (self.width - shown) as u16;
+                    self.send_tile_part(data, width, &part, x, center_y)?;
+                }
+            }
+
+            if step < steps {
+                delay.delay_ms(step_ms);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sends part of a tile to a position on the display.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Tile pixels in RGB565 format.
+    /// * `tile_width` - Width of the tile.
+    /// * `part` - The part of the tile to send, in tile coordinates.
+    /// * `x` - Where the part's left edge goes on the display.
+    /// * `y` - Where the part's top edge goes on the display.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn send_tile_part(
+        &mut self,
+        data: &[u8],
+        tile_width: u32,
+        part: &Region,
+        x: u16,
+        y: u16,
+    ) -> Result<(), Error> {
+        if part.is_empty() {
+            return Ok(());
+        }
+
+        self.set_address_window_region(&Region {
+            x,
+            y,
+            width: part.width,
+            height: part.height,
+        })?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        for row in part.y as u32..part.y as u32 + part.height {
+            let start = ((row * tile_width + part.x as u32) as usize) * BYTES_PER_PIXEL;
+            let end = start + part.width as usize * BYTES_PER_PIXEL;
+            for chunk in data[start..end].chunks(self.chunk_size) {
+                self.write_data(chunk)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Fades the screen from a full-screen buffer to a solid color.
+    ///
+    /// Each intermediate frame is computed on the fly in small chunks and streamed to the
+    /// display, so no extra frame buffer is needed. The last frame is the solid color.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Full-screen buffer in RGB565 format to fade from.
+    /// * `to_color` - The color to fade to.
+    /// * `steps` - Number of frames to push. Zero is treated as one, showing just the
+    ///   solid color.
+    /// * `delay` - Delay provider.
+    /// * `step_ms` - Delay between frames in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn fade_to<DELAY>(
+        &mut self,
+        from: &[u8],
+        to_color: Rgb565,
+        steps: u8,
+        delay: &mut DELAY,
+        step_ms: u32,
+    ) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        self.ensure_initialized()?;
+
+        let frame_len = (self.width * self.height) as usize * 2;
+        if from.len() < frame_len {
+            return Err(Error::BufferSize);
+        }
+
+        let target = to_color.into_storage();
+        let steps = steps.max(1) as u32;
+        const CHUNK_SIZE: usize = 512;
+        let mut chunk = [0u8; CHUNK_SIZE];
+
+        for step in 1..=steps {
+            self.set_address_window(0, 0, self.width as u16 - 1, self.height as u16 - 1)?;
+            self.write_command(Instruction::RamWr as u8, &[])?;
+            self.start_data()?;
+
+            for src in from[..frame_len].chunks(CHUNK_SIZE) {
+                for (dst, pixel) in chunk.chunks_exact_mut(2).zip(src.chunks_exact(2)) {
+                    let color = u16::from_be_bytes([pixel[0], pixel[1]]);
+                    let blended = color::blend_rgb565(color, target, step, steps);
+                    dst.copy_from_slice(&blended.to_be_bytes());
+                }
+                self.write_data(&chunk[..src.len()])?;
+            }
+
+            delay.delay_ms(step_ms);
+        }
+
+        Ok(())
+    }
+
+    /// Starts a non-blocking full-screen update.
+    ///
+    /// This sets the address window and starts the memory write; the pixel data is then
+    /// sent a chunk at a time by calling `poll_show` until it returns `Poll::Ready`. No other
+    /// drawing method should be called on the display until then.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Buffer to display, containing pixel data for the full display area.
+    ///
+    /// # Returns
+    ///
+    /// The state of the update to pass to `poll_show`, or an error.
+    pub fn begin_show<'b>(&mut self, buffer: &'b [u8]) -> Result<ShowInProgress<'b>, Error> {
+        self.ensure_initialized()?;
+
+        self.set_address_window(0, 0, self.width as u16 - 1, self.height as u16 - 1)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        Ok(ShowInProgress { buffer, sent: 0 })
+    }
+
+    /// Sends the next chunk of a non-blocking update started with `begin_show`.
+    ///
+    /// # Arguments
+    ///
+    /// * `show` - The update in progress.
+    ///
+    /// # Returns
+    ///
+    /// `Poll::Pending` while data remains, `Poll::Ready(Ok(()))` once the whole buffer has
+    /// been sent, or `Poll::Ready(Err(_))` if a transfer failed.
+    pub fn poll_show(&mut self, show: &mut ShowInProgress<'_>) -> Poll<Result<(), Error>> {
+        if show.is_done() {
+            return Poll::Ready(Ok(()));
+        }
+
+        let end = (show.sent + ShowInProgress::CHUNK_SIZE).min(show.buffer.len());
+        if let Err(e) = self.write_data(&show.buffer[show.sent..end]) {
+            return Poll::Ready(Err(e));
+        }
+        show.sent = end;
+
+        if show.is_done() {
+            Poll::Ready(Ok(()))
+        } else {
+            Poll::Pending
+        }
+    }
+
+    /// Updates only the specified region of the display with the provided buffer.
+    ///
+    /// This function updates a specified rectangular region of the display with the pixel data
+    /// provided in the buffer. It calculates the necessary offsets and addresses to update only
+    /// the designated area, ensuring efficient display refresh.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A slice of bytes representing the pixel data in RGB565 format.
+    /// * `top_left_x` - The x-coordinate of the top-left corner of the region to update.
+    /// * `top_left_y` - The y-coordinate of the top-left corner of the region to update.
+    /// * `width` - The width of the region to update.
+    /// * `height` - The height of the region to update.
+    ///
+    /// A region with zero width or height is a no-op.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success (`Ok`) or failure (`Err`).
+    pub fn show_region(
+        &mut self,
+        buffer: &[u8],
+        top_left_x: u16,
+        top_left_y: u16,
+        width: u32,
+        height: u32,
+    ) -> Result<(), Error> {
+        self.ensure_initialized()?;
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        #[cfg(feature = "metrics")]
+        let started = self.clock.map(|clock| clock());
+
+        let start_x = top_left_x; // Start x-coordinate
+        let start_y = top_left_y; // Start y-coordinate
+        let end_x = Self::region_end(top_left_x, width)?; // End x-coordinate
+        let end_y = Self::region_end(top_left_y, height)?; // End y-coordinate
+
+        // Calculate the buffer offset for the region
+        let buffer_width = self.width as usize; // Width of the buffer
+
+        // Make sure the last row of the region lies inside the buffer before sending anything
+        let last_row_end = (end_y as usize)
+            .checked_mul(buffer_width)
+            .and_then(|i| i.checked_add(start_x as usize))
+            .and_then(|i| i.checked_add(width as usize))
+            .and_then(|i| i.checked_mul(BYTES_PER_PIXEL))
+            .ok_or(Error::OutOfBounds)?;
+        if last_row_end > buffer.len() {
+            return Err(Error::OutOfBounds);
+        }
+
+        // Set the address window for the region to be updated
+        self.set_address_window(start_x, start_y, end_x, end_y)?;
+
+        // Send the command to write to RAM
+        self.write_command(Instruction::RamWr as u8, &[])?;
+
+        // Start data transmission
+        self.start_data()?;
+
+        // Iterate over each row in the region
+        for y in start_y..=end_y {
+            let start_index = ((y as usize) * buffer_width + (start_x as usize)) * BYTES_PER_PIXEL;
+            let end_index = start_index + (width as usize) * BYTES_PER_PIXEL;
+            debug_assert!(end_index <= last_row_end);
+
+            if self.software_brightness < 255 {
+                self.write_dimmed(&buffer[start_index..end_index], y == start_y)?;
+                continue;
+            }
+
+            // Write data to the display in chunks of the configured size
+            for chunk in buffer[start_index..end_index].chunks(self.chunk_size) {
+                self.write_data(chunk)?;
+            }
+        }
+
+        #[cfg(feature = "metrics")]
+        self.record_timing(started);
+
+        Ok(())
+    }
+
+    /// Returns the last coordinate covered by a span, checking for overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - First coordinate of the span.
+    /// * `length` - Length of the span.
+    ///
+    /// # Returns
+    ///
+    /// The end coordinate, or `Error::OutOfBounds` if the span is empty or does not fit
+    /// in `u16` coordinates.
+    fn region_end(start: u16, length: u32) -> Result<u16, Error> {
+        let end = (start as u32)
+            .checked_add(length)
+            .and_then(|end| end.checked_sub(1))
+            .ok_or(Error::OutOfBounds)?;
+        let end = u16::try_from(end).map_err(|_| Error::OutOfBounds)?;
+        debug_assert!(end >= start);
+        Ok(end)
+    }
+
+    /// Updates a region of the display from an iterator of rows.
+    ///
+    /// The address window is set once and each row is streamed as it is produced, so region
+    /// content can be generated procedurally without a frame buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to update.
+    /// * `rows` - One item per region row, each `region.width * 2` bytes of RGB565 data.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>`, with `Error::BufferSize` if a row has the wrong length or the
+    /// number of rows does not match the region height. Rows before the offending one have
+    /// already been sent.
+    pub fn show_region_rows<'r, I>(&mut self, region: Region, rows: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = &'r [u8]>,
+    {
+        self.ensure_initialized()?;
+
+        if region.is_empty() {
+            return Ok(());
+        }
+
+        let end_x = Self::region_end(region.x, region.width)?;
+        let end_y = Self::region_end(region.y, region.height)?;
+        let row_bytes = region.width as usize * 2;
+
+        self.set_address_window(region.x, region.y, end_x, end_y)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        let mut count = 0;
+        for row in rows {
+            if row.len() != row_bytes || count == region.height {
+                return Err(Error::BufferSize);
+            }
+            self.write_data(row)?;
+            count += 1;
+        }
+
+        if count != region.height {
+            return Err(Error::BufferSize);
+        }
+
+        Ok(())
+    }
+
+    /// Fills a region of the display from an iterator of colors.
+    ///
+    /// The colors are packed to big-endian RGB565 and streamed in chunks, row by row from
+    /// the top-left corner. Streaming stops when the iterator ends or the region is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to fill.
+    /// * `colors` - The pixel colors.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::InvalidArgument` if
+    /// the region's pixel count overflows.
+    pub fn draw_colors<I>(&mut self, region: Region, colors: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = Rgb565>,
+    {
+        self.ensure_initialized()?;
+
+        if region.is_empty() {
+            return Ok(());
+        }
+
+        let total_pixels = region
+            .width
+            .checked_mul(region.height)
+            .ok_or(Error::InvalidArgument)? as usize;
+        self.set_address_window_region(&region)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        const CHUNK_SIZE: usize = 512;
+        let mut chunk = [0u8; CHUNK_SIZE];
+        let mut len = 0;
+
+        for color in colors.into_iter().take(total_pixels) {
+            chunk[len..len + 2].copy_from_slice(&color.into_storage().to_be_bytes());
+            len += 2;
+            if len == CHUNK_SIZE {
+                self.write_data(&chunk)?;
+                len = 0;
+            }
+        }
+        if len > 0 {
+            self.write_data(&chunk[..len])?;
+        }
+
+        Ok(())
+    }
+
+    /// Opens an address window for pixels pushed in several calls.
+    ///
+    /// The window and RamWr are sent once; the returned `PixelWriter` then streams pixels
+    /// into the window in order until it is dropped or finished.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to write.
+    ///
+    /// # Returns
+    ///
+    /// A `PixelWriter` for the region, or an error if the window could not be set. For an
+    /// empty region nothing is sent and the writer drops every pixel pushed to it.
+    pub fn begin_pixels(
+        &mut self,
+        region: Region,
+    ) -> Result<PixelWriter<'_, SPI, DC, CS, RST>, Error> {
+        self.ensure_initialized()?;
+
+        if region.is_empty() {
+            return Ok(PixelWriter::empty(self));
+        }
+
+        self.set_address_window_region(&region)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        Ok(PixelWriter::new(self))
+    }
+
+    /// Fills the whole screen by repeating a small pattern.
+    ///
+    /// The pattern is tiled from the top-left corner and streamed row by row, so no
+    /// full-screen buffer is needed. A pattern larger than the screen is clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - Pattern pixels in RGB565 format, `pattern_w * pattern_h * 2` bytes.
+    /// * `pattern_w` - Width of the pattern.
+    /// * `pattern_h` - Height of the pattern.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::BufferSize` if the
+    /// pattern is empty or shorter than its dimensions.
+    pub fn fill_tiled(
+        &mut self,
+        pattern: &[u8],
+        pattern_w: u32,
+        pattern_h: u32,
+    ) -> Result<(), Error> {
+        self.ensure_initialized()?;
+
+        if pattern_w == 0
+            || pattern_h == 0
+            || (pattern.len() as u64) < pattern_w as u64 * pattern_h as u64 * 2
+        {
+            return Err(Error::BufferSize);
+        }
+
+        let width = self.width as u16;
+        let height = self.height as u16;
+
+        self.set_address_window(0, 0, width - 1, height - 1)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        const CHUNK_SIZE: usize = 512;
+        let mut chunk = [0u8; CHUNK_SIZE];
+        let mut len = 0;
+
+        for y in 0..self.height {
+            let row_start = ((y % pattern_h) * pattern_w) as usize * 2;
+            for x in 0..self.width {
+                let index = row_start + (x % pattern_w) as usize * 2;
+                chunk[len..len + 2].copy_from_slice(&pattern[index..index + 2]);
+                len += 2;
+                if len == CHUNK_SIZE {
+                    self.write_data(&chunk)?;
+                    len = 0;
+                }
+            }
+        }
+        if len > 0 {
+            self.write_data(&chunk[..len])?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates a region of the display with frame data streamed from a reader.
+    ///
+    /// This works like `show_region`, but pulls each row from a `FrameReader` on demand
+    /// instead of borrowing the frame as a slice, so the frame can live in external memory.
+    ///
+    /// The reader is borrowed mutably because reading external memory usually means
+    /// driving another bus, such as the QSPI peripheral in front of a PSRAM, which needs
+    /// exclusive access; `FrameReader::read` takes `&mut self` for the same reason.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Source of full-screen frame data in RGB565 format.
+    /// * `region` - The region to update.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success (`Ok`) or failure (`Err`), with
+    /// `Error::OutOfBounds` if the region extends past the display.
+    pub fn show_from<R>(&mut self, reader: &mut R, region: Region) -> Result<(), Error>
+    where
+        R: FrameReader,
+    {
+        self.ensure_initialized()?;
+
+        if region.is_empty() {
+            return Ok(());
+        }
+
+        let end_x = Self::region_end(region.x, region.width)?;
+        let end_y = Self::region_end(region.y, region.height)?;
+        if end_x as u32 >= self.width || end_y as u32 >= self.height {
+            return Err(Error::OutOfBounds);
+        }
+
+        self.set_address_window(region.x, region.y, end_x, end_y)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        const CHUNK_SIZE: usize = 512;
+        let mut chunk = [0u8; CHUNK_SIZE];
+        let row_bytes = region.width as usize * 2;
+
+        for y in region.y..=end_y {
+            let mut offset = ((y as usize) * self.width as usize + region.x as usize) * 2;
+            let mut remaining = row_bytes;
+
+            while remaining > 0 {
+                let len = remaining.min(CHUNK_SIZE);
+                reader
+                    .read(offset, &mut chunk[..len])
+                    .map_err(|_| Error::Read)?;
+                self.write_data(&chunk[..len])?;
+                offset += len;
+                remaining -= len;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn store_region(&mut self, region: Region) -> Result<(), Error> {
+        if !self.region_tracking || region.is_empty() {
+            return Ok(());
+        }
+
+        for i in 0..self.regions.len() {
+            if self.regions[i].is_none() {
+                self.regions[i] = Some(region);
+                return Ok(());
+            }
+        }
+        Err(Error::RegionsFull)
+    }
+
+    /// Enables or disables region tracking.
+    ///
+    /// While disabled, `store_region` does nothing and returns `Ok`, so drawing that
+    /// should not be part of the next partial update (such as a static background element)
+    /// is not recorded. Tracking is enabled by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether regions are stored.
+    pub fn set_region_tracking(&mut self, enabled: bool) {
+        self.region_tracking = enabled;
+    }
+
+    pub fn store_region_from_params(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u32,
+        height: u32,
+    ) -> Result<(), Error> {
+        let region = Region {
+            x,
+            y,
+            width,
+            height,
+        };
+
+        self.store_region(region)
+    }
+
+    pub fn get_regions(&self) -> &[Option<Region>] {
+        &self.regions
+    }
+
+    /// Checks every stored region against the display bounds.
+    ///
+    /// Call this before `show_regions` to reject a bad batch up front instead of failing
+    /// partway through a frame. Regions with zero width or height are valid; showing them
+    /// does nothing.
+    ///
+    /// # Returns
+    ///
+    /// `Ok(())` if every region can be shown, or the index into `get_regions` of the first
+    /// region that cannot and the reason.
+    pub fn validate_regions(&self) -> Result<(), (usize, RegionError)> {
+        for (index, region) in self.regions.iter().enumerate() {
+            let Some(region) = region else {
+                continue;
+            };
+            if region.is_empty() {
+                continue;
+            }
+
+            let end_x = region.x as u64 + region.width as u64 - 1;
+            let end_y = region.y as u64 + region.height as u64 - 1;
+            if end_x > u16::MAX as u64 || end_y > u16::MAX as u64 {
+                return Err((index, RegionError::Overflow));
+            }
+            if end_x >= self.width as u64 || end_y >= self.height as u64 {
+                return Err((index, RegionError::OutOfBounds));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds the stored region under a point, such as a touch position.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate.
+    /// * `y` - Y-coordinate.
+    ///
+    /// # Returns
+    ///
+    /// The index into `get_regions` of the first region containing the point, or `None`.
+    pub fn region_at(&self, x: u16, y: u16) -> Option<usize> {
+        self.regions
+            .iter()
+            .position(|region| region.is_some_and(|region| region.contains(x, y)))
+    }
+
+    pub fn clear_regions(&mut self) {
+        self.regions = [None; 10];
+    }
+
+    /// Updates every stored region of the display from a full-screen buffer.
+    ///
+    /// Regions stacked directly on top of each other with the same x and width are sent
+    /// as one taller region, with a single address window.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - Full-screen buffer in RGB565 format.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>`, with `Error::BufferSize` if the buffer is smaller than the
+    /// display. Nothing is sent in that case.
+    pub fn show_regions(&mut self, buffer: &[u8]) -> Result<(), Error> {
+        if buffer.len() < (self.width * self.height) as usize * 2 {
+            return Err(Error::BufferSize);
+        }
+
+        // Merge vertically stacked regions of the same column span so they share a window
+        let mut regions = self.regions;
+        let mut merged = true;
+        while merged {
+            merged = false;
+            for i in 0..regions.len() {
+                for j in i + 1..regions.len() {
+                    if let (Some(a), Some(b)) = (regions[i], regions[j]) {
+                        if let Some(stacked) = Self::stack_regions(a, b) {
+                            regions[i] = Some(stacked);
+                            regions[j] = None;
+                            merged = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        for region_data in regions.into_iter().flatten() {
+            self.show_region(
+                buffer,
+                region_data.x,
+                region_data.y,
+                region_data.width,
+                region_data.height,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Combines two regions when one sits directly on top of the other with the same
+    /// columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first region.
+    /// * `b` - The second region.
+    ///
+    /// # Returns
+    ///
+    /// The combined region, or `None` if the regions are not stacked.
+    fn stack_regions(a: Region, b: Region) -> Option<Region> {
+        if a.x != b.x || a.width != b.width {
+            return None;
+        }
+
+        let (top, bottom) = if a.y <= b.y { (a, b) } else { (b, a) };
+        if top.y as u32 + top.height != bottom.y as u32 {
+            return None;
+        }
+
+        Some(Region {
+            height: top.height + bottom.height,
+            ..top
+        })
+    }
+
+    /// Fills every stored region on the display with a solid color and clears the list.
+    ///
+    /// This restores the panel under the regions when the background is a single color,
+    /// without needing a background frame buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The background color, in RGB565 format.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn clear_regions_on_panel(&mut self, color: u16) -> Result<(), Error> {
+        for region in self.regions.into_iter().flatten() {
+            self.clear_region(region, color)?;
+        }
+        self.clear_regions();
+        Ok(())
+    }
+
+    // Additional function with default parameter
+    pub fn show_regions_and_clear(&mut self, buffer: &[u8]) -> Result<(), Error> {
+        self.show_regions(buffer)?;
+        self.clear_regions();
+        Ok(())
+    }
+}
+
+// Pixel streaming for buses that also support 16-bit words
+impl<SPI, DC, CS, RST> GC9A01A<SPI, DC, CS, RST>
+where
+    SPI: SpiDevice + SpiDevice<u16>,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    /// Writes 16-bit data words to the display.
+    ///
+    /// Each word is sent as a single SPI word, most significant bit first, which matches
+    /// the RGB565 byte order expected by the display. Pixel data is also copied into the
+    /// mirror, as with `write_data`.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Data words to write.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn write_data_u16(&mut self, data: &[u16]) -> Result<(), Error> {
+        self.send_data_u16(data)?;
+        if self.ram_write_open {
+            for word in data {
+                self.mirror_stream(&word.to_be_bytes());
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends 16-bit data words to the display without updating the mirror.
+    ///
+    /// # Arguments
+    ///
+    /// * `data` - Data words to write.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    fn send_data_u16(&mut self, data: &[u16]) -> Result<(), Error> {
+        self.cs.set_high().map_err(|_| Error::Pin)?;
+        self.dc.set_high().map_err(|_| Error::Pin)?;
+        self.cs.set_low().map_err(|_| Error::Pin)?;
+        #[cfg(feature = "record")]
+        if let Some(recorded) = self.recorded.as_mut() {
+            for word in data {
+                recorded.extend_from_slice(&word.to_be_bytes());
+            }
+            self.cs.set_high().map_err(|_| Error::Pin)?;
+            return Ok(());
+        }
+        if SpiDevice::<u16>::write(&mut self.spi, data).is_err() {
+            return Err(self.abort_transfer());
+        }
+        self.cs.set_high().map_err(|_| Error::Pin)?;
+        Ok(())
+    }
+
+    /// Clears the screen with a single color using 16-bit SPI words.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to fill the screen with, in RGB565 format.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn clear_screen_u16(&mut self, color: u16) -> Result<(), Error> {
+        self.ensure_initialized()?;
+
+        self.set_address_window(0, 0, self.width as u16 - 1, self.height as u16 - 1)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        const CHUNK_SIZE: usize = 512;
+        let chunk = [color; CHUNK_SIZE];
+
+        let total_pixels = (self.width * self.height) as usize;
+        for _ in 0..total_pixels / CHUNK_SIZE {
+            self.write_data_u16(&chunk)?;
+        }
+
+        let remaining_pixels = total_pixels % CHUNK_SIZE;
+        if remaining_pixels > 0 {
+            self.write_data_u16(&chunk[..remaining_pixels])?;
+        }
+
+        Ok(())
+    }
+
+    /// Displays a full-screen buffer of RGB565 pixels using 16-bit SPI words.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - One RGB565 value per pixel for the full display area.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::BufferSize` if the
+    /// buffer does not cover the full display.
+    pub fn show_u16(&mut self, buffer: &[u16]) -> Result<(), Error> {
+        self.ensure_initialized()?;
+        let buffer = buffer
+            .get(..self.width as usize * self.height as usize)
+            .ok_or(Error::BufferSize)?;
+
+        self.set_address_window(0, 0, self.width as u16 - 1, self.height as u16 - 1)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.write_data_u16(buffer)
+    }
+
+    /// Updates a region of the display from a full-screen buffer of RGB565 pixels using
+    /// 16-bit SPI words.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - One RGB565 value per pixel for the full display area.
+    /// * `region` - The region to update.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure, with `Error::OutOfBounds` if the
+    /// region extends past the display and `Error::BufferSize` if the buffer does not cover
+    /// the full display.
+    pub fn show_region_u16(&mut self, buffer: &[u16], region: Region) -> Result<(), Error> {
+        self.ensure_initialized()?;
+
+        if region.is_empty() {
+            return Ok(());
+        }
+
+        let end_x = Self::region_end(region.x, region.width)?;
+        let end_y = Self::region_end(region.y, region.height)?;
+        if end_x as u32 >= self.width || end_y as u32 >= self.height {
+            return Err(Error::OutOfBounds);
+        }
+        if buffer.len() < self.width as usize * self.height as usize {
+            return Err(Error::BufferSize);
+        }
+
+        self.set_address_window(region.x, region.y, end_x, end_y)?;
+        self.write_command(Instruction::RamWr as u8, &[])?;
+        self.start_data()?;
+
+        for y in region.y..=end_y {
+            let start_index = (y as usize) * self.width as usize + region.x as usize;
+            let end_index = start_index + region.width as usize;
+            self.write_data_u16(&buffer[start_index..end_index])?;
+        }
+
+        Ok(())
+    }
+}
+
+// Implementing the DrawTarget trait for the GC9A01A display driver
+impl<SPI, DC, CS, RST> DrawTarget for GC9A01A<SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    type Color = Rgb565;
+    type Error = Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(coord, color) in pixels.into_iter() {
+            let color_value = color.into_storage();
+            // Only draw pixels that would be on screen
+            if coord.x >= 0
+                && coord.y >= 0
+                && coord.x < self.width as i32
+                && coord.y < self.height as i32
+            {
+                self.write_pixel(coord.x as u16, coord.y as u16, color_value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        // Stream the color in one window instead of addressing every pixel
+        self.clear_screen(color.into_storage())
+    }
+}
+
+// Implementing the OriginDimensions trait for the GC9A01A display driver
+impl<SPI, DC, CS, RST> OriginDimensions for GC9A01A<SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+}
+
+/// A structure representing a frame buffer.
+pub struct FrameBuffer<'a> {
+    buffer: &'a mut [u8],
+    /// Width in the buffer's orientation.
+    width: u32,
+    /// Height in the buffer's orientation.
+    height: u32,
+    /// Display orientation the buffer is laid out for.
+    orientation: Orientation,
+}
+
+impl<'a> FrameBuffer<'a> {
+    /// Creates a new frame buffer.
+    ///
+    /// The frame buffer may be smaller than the display, to hold just a widget area in a
+    /// small backing buffer; `GC9A01A::show_framebuffer_at` sends it to its place on the
+    /// panel.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A mutable slice representing the pixel data.
+    /// * `width` - The width of the frame buffer.
+    /// * `height` - The height of the frame buffer.
+    pub fn new(buffer: &'a mut [u8], width: u32, height: u32) -> Self {
+        Self {
+            buffer,
+            width,
+            height,
+            orientation: Orientation::Portrait,
+        }
+    }
+
+    /// Creates a new frame buffer laid out for a display orientation.
+    ///
+    /// `width` and `height` are the display's native (`Portrait`) dimensions. For the
+    /// landscape orientations they are swapped, so the buffer's rows match what the display
+    /// scans after `set_orientation` with the same orientation. Drawing, `size` and
+    /// `copy_region` all use the swapped dimensions. Use the same orientation for the
+    /// display and its frame buffers, otherwise the image appears transposed.
+    ///
+    /// The orientation is stored as a runtime value, not as part of the type, so a
+    /// mismatch with the display is not caught at compile time, and nothing checks it when
+    /// the buffer is shown either. Compare `orientation` with the value passed to
+    /// `GC9A01A::set_orientation` where rotation can change at run time.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A mutable slice representing the pixel data.
+    /// * `width` - The native width of the display.
+    /// * `height` - The native height of the display.
+    /// * `orientation` - The orientation the display is set to.
+    pub fn with_orientation(
+        buffer: &'a mut [u8],
+        width: u32,
+        height: u32,
+        orientation: Orientation,
+    ) -> Self {
+        let (width, height) = if orientation as u8 & MADCTL_MV != 0 {
+            (height, width)
+        } else {
+            (width, height)
+        };
+
+        Self {
+            buffer,
+            width,
+            height,
+            orientation,
+        }
+    }
+
+    /// Returns the orientation the frame buffer is laid out for.
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// Returns a reference to the buffer.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the buffer.
+    pub fn get_buffer(&self) -> &[u8] {
+        self.buffer
+    }
+
+    /// Consumes the frame buffer and returns the underlying slice.
+    ///
+    /// This hands a rendered frame to another owner, such as a queue to the core that
+    /// sends frames to the display, without copying it.
+    pub fn into_buffer(self) -> &'a mut [u8] {
+        self.buffer
+    }
+
+    /// Returns the number of bytes each pixel takes in the buffer.
+    pub fn bytes_per_pixel(&self) -> usize {
+        BYTES_PER_PIXEL
+    }
+
+    /// Returns the number of bytes in one row of the buffer.
+    pub fn stride(&self) -> usize {
+        self.width as usize * BYTES_PER_PIXEL
+    }
+
+    /// Returns the frame buffer as an embedded-graphics raw image.
+    ///
+    /// This allows drawing the frame buffer into another `DrawTarget` with `Image`.
+    pub fn as_image_raw(&self) -> ImageRaw<'_, Rgb565> {
+        ImageRaw::new(self.buffer, self.width)
+    }
+
+    /// Returns the color of the pixel at the given coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate.
+    /// * `y` - Y-coordinate.
+    ///
+    /// # Returns
+    ///
+    /// The pixel color, or `None` if the coordinates are outside the frame buffer.
+    pub fn get_pixel(&self, x: u16, y: u16) -> Option<Rgb565> {
+        if x as u32 >= self.width || y as u32 >= self.height {
+            return None;
+        }
+
+        let index = ((y as u32 * self.width + x as u32) * 2) as usize;
+        let bytes = self.buffer.get(index..index + 2)?;
+        let raw_color = u16::from_be_bytes([bytes[0], bytes[1]]);
+        Some(Rgb565::from(RawU16::new(raw_color)))
+    }
+
+    /// Clears the frame buffer with the specified color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to clear the buffer with.
+    pub fn clear(&mut self, color: Rgb565) {
+        color::fill_chunk_rgb565(self.buffer, color.into_storage());
+    }
+
+    /// Overwrites the whole frame buffer with a pre-rendered background.
+    ///
+    /// # Arguments
+    ///
+    /// * `template` - The background buffer, the same size as this one.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>`, with `Error::BufferSize` if the template differs in size.
+    pub fn fill_from_template(&mut self, template: &[u8]) -> Result<(), Error> {
+        if template.len() != self.buffer.len() {
+            return Err(Error::BufferSize);
+        }
+
+        self.buffer.copy_from_slice(template);
+        Ok(())
+    }
+
+    /// Copies a region from another buffer into this buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `src_buffer` - The source buffer.
+    /// * `src_x` - The x-coordinate of the top-left corner of the source region.
+    /// * `src_y` - The y-coordinate of the top-left corner of the source region.
+    /// * `src_width` - The width of the source region.
+    /// * `src_height` - The height of the source region.
+    /// * `dest_x` - The x-coordinate of the top-left corner of the destination region.
+    /// * `dest_y` - The y-coordinate of the top-left corner of the destination region.
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_region(
+        &mut self,
+        src_buffer: &[u8],
+        src_x: u16,
+        src_y: u16,
+        src_width: u32,
+        src_height: u32,
+        dest_x: u16,
+        dest_y: u16,
+    ) {
+        for row in 0..src_height as usize {
+            let src_row_start =
+                (src_y as usize + row) * self.width as usize * 2 + src_x as usize * 2;
+            let src_row_end = src_row_start + src_width as usize * 2;
+
+            let dest_row_start =
+                (dest_y as usize + row) * self.width as usize * 2 + dest_x as usize * 2;
+            let dest_row_end = dest_row_start + src_width as usize * 2;
+
+            self.buffer[dest_row_start..dest_row_end]
+                .copy_from_slice(&src_buffer[src_row_start..src_row_end]);
+        }
+    }
+
+    /// Restores regions from a source buffer into the frame buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `src_buffer` - The source buffer.
+    /// * `regions` - An array of regions to restore.
+    pub fn copy_regions(&mut self, src_buffer: &[u8], regions: &[Option<Region>]) {
+        for region in regions.iter().flatten() {
+            self.copy_region(
+                src_buffer,
+                region.x,
+                region.y,
+                region.width,
+                region.height,
+                region.x,
+                region.y,
+            );
+        }
+    }
+
+    /// Restores regions from a source buffer, each expanded by a margin.
+    ///
+    /// The margin covers anti-aliasing fringes just outside a region. Expanded regions are
+    /// clamped to the frame buffer bounds.
+    ///
+    /// # Arguments
+    ///
+    /// * `src_buffer` - The source buffer.
+    /// * `regions` - An array of regions to restore.
+    /// * `padding` - Number of pixels to add on every side of each region.
+    pub fn copy_regions_padded(
+        &mut self,
+        src_buffer: &[u8],
+        regions: &[Option<Region>],
+        padding: u16,
+    ) {
+        for region in regions.iter().flatten() {
+            let x = region.x.saturating_sub(padding);
+            let y = region.y.saturating_sub(padding);
+            let right = (region.x as u32 + region.width + padding as u32).min(self.width);
+            let bottom = (region.y as u32 + region.height + padding as u32).min(self.height);
+            if right <= x as u32 || bottom <= y as u32 {
+                continue;
+            }
+
+            self.copy_region(src_buffer, x, y, right - x as u32, bottom - y as u32, x, y);
+        }
+    }
+
+    /// Sets every pixel to a blend of the corresponding pixels of two buffers.
+    ///
+    /// This is the per-frame step of a crossfade between two screens: `t = 0` gives `a`,
+    /// `t = 255` gives `b`.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The buffer to fade from.
+    /// * `b` - The buffer to fade to.
+    /// * `t` - Blend position from 0 to 255.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>`, with `Error::BufferSize` if the three buffers differ in size.
+    pub fn crossfade_from(&mut self, a: &[u8], b: &[u8], t: u8) -> Result<(), Error> {
+        if a.len() != self.buffer.len() || b.len() != self.buffer.len() {
+            return Err(Error::BufferSize);
+        }
+
+        for ((dst, a), b) in self
+            .buffer
+            .chunks_exact_mut(2)
+            .zip(a.chunks_exact(2))
+            .zip(b.chunks_exact(2))
+        {
+            let from = u16::from_be_bytes([a[0], a[1]]);
+            let to = u16::from_be_bytes([b[0], b[1]]);
+            let blended = color::blend_rgb565(from, to, t as u32, 255);
+            dst.copy_from_slice(&blended.to_be_bytes());
+        }
+
+        Ok(())
+    }
+
+    /// Draws a downscaled copy of another buffer into a region, for thumbnails.
+    ///
+    /// Each destination pixel is the average of a `factor` by `factor` block of source
+    /// pixels. The copy is clipped to the region, to this frame buffer and to the source.
+    ///
+    /// # Arguments
+    ///
+    /// * `src` - Source pixels in RGB565 format, `src_w * src_h * 2` bytes.
+    /// * `src_w` - Width of the source.
+    /// * `src_h` - Height of the source.
+    /// * `dst_region` - Where the thumbnail is drawn.
+    /// * `factor` - How many source pixels map to one destination pixel along each axis.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>`, with `Error::BufferSize` if the source data is too short or
+    /// `factor` is zero.
+    pub fn downscale_into(
+        &mut self,
+        src: &[u8],
+        src_w: u32,
+        src_h: u32,
+        dst_region: &Region,
+        factor: u8,
+    ) -> Result<(), Error> {
+        if factor == 0 || src.len() < (src_w * src_h * 2) as usize {
+            return Err(Error::BufferSize);
+        }
+        let factor = factor as u32;
+
+        let width = dst_region
+            .width
+            .min(self.width.saturating_sub(dst_region.x as u32))
+            .min(src_w.div_ceil(factor));
+        let height = dst_region
+            .height
+            .min(self.height.saturating_sub(dst_region.y as u32))
+            .min(src_h.div_ceil(factor));
+
+        for y in 0..height {
+            for x in 0..width {
+                let (mut r, mut g, mut b, mut count) = (0u32, 0u32, 0u32, 0u32);
+                for sy in y * factor..((y + 1) * factor).min(src_h) {
+                    for sx in x * factor..((x + 1) * factor).min(src_w) {
+                        let index = ((sy * src_w + sx) * 2) as usize;
+                        let pixel = u16::from_be_bytes([src[index], src[index + 1]]) as u32;
+                        r += pixel >> 11;
+                        g += (pixel >> 5) & 0x3F;
+                        b += pixel & 0x1F;
+                        count += 1;
+                    }
+                }
+
+                let pixel = ((r / count) << 11 | (g / count) << 5 | (b / count)) as u16;
+                let index = (((dst_region.y as u32 + y) * self.width + dst_region.x as u32 + x) * 2)
+                    as usize;
+                self.buffer[index..index + 2].copy_from_slice(&pixel.to_be_bytes());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Writes a rotated copy of this frame buffer into another frame buffer.
+    ///
+    /// For 90 and 270 degrees the destination must have this buffer's width and height
+    /// swapped; otherwise it must have the same dimensions.
+    ///
+    /// # Arguments
+    ///
+    /// * `dst` - The destination frame buffer.
+    /// * `rotation` - The clockwise rotation to apply.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>`, with `Error::BufferSize` if the destination dimensions do not
+    /// match the rotated size.
+    pub fn rotated_into(&self, dst: &mut FrameBuffer, rotation: Rotation) -> Result<(), Error> {
+        let (w, h) = (self.width as usize, self.height as usize);
+        let (dst_w, dst_h) = match rotation {
+            Rotation::Deg0 | Rotation::Deg180 => (w, h),
+            Rotation::Deg90 | Rotation::Deg270 => (h, w),
+        };
+        if dst.width as usize != dst_w
+            || dst.height as usize != dst_h
+            || self.buffer.len() < w * h * 2
+            || dst.buffer.len() < dst_w * dst_h * 2
+        {
+            return Err(Error::BufferSize);
+        }
+
+        for y in 0..h {
+            for x in 0..w {
+                let (dx, dy) = match rotation {
+                    Rotation::Deg0 => (x, y),
+                    Rotation::Deg90 => (h - 1 - y, x),
+                    Rotation::Deg180 => (w - 1 - x, h - 1 - y),
+                    Rotation::Deg270 => (y, w - 1 - x),
+                };
+                let src_index = (y * w + x) * 2;
+                let dst_index = (dy * dst_w + dx) * 2;
+                dst.buffer[dst_index..dst_index + 2]
+                    .copy_from_slice(&self.buffer[src_index..src_index + 2]);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rotates the frame buffer contents by 180 degrees in place.
+    pub fn rotate_180(&mut self) {
+        let pixels = (self.width * self.height) as usize;
+        for i in 0..pixels / 2 {
+            let a = i * 2;
+            let b = (pixels - 1 - i) * 2;
+            self.buffer.swap(a, b);
+            self.buffer.swap(a + 1, b + 1);
+        }
+    }
+
+    /// Reflects the top-left quadrant into the other three quadrants.
+    ///
+    /// The top-right quadrant becomes a horizontal mirror, the bottom-left a vertical
+    /// mirror and the bottom-right both. With an odd width or height, the center column or
+    /// row belongs to the top-left quadrant and is its own mirror image.
+    pub fn mirror_quadrants(&mut self) {
+        let w = self.width as usize;
+        let h = self.height as usize;
+
+        for y in 0..h.div_ceil(2) {
+            for x in 0..w.div_ceil(2) {
+                let src = (y * w + x) * 2;
+                let pixel = [self.buffer[src], self.buffer[src + 1]];
+                for (mx, my) in [(w - 1 - x, y), (x, h - 1 - y), (w - 1 - x, h - 1 - y)] {
+                    let dst = (my * w + mx) * 2;
+                    self.buffer[dst..dst + 2].copy_from_slice(&pixel);
+                }
+            }
+        }
+    }
+
+    /// Splits the frame buffer into horizontal bands.
+    ///
+    /// Each band holds whole rows, so it can be rendered and sent with `draw_image_sized`
+    /// on its own and the first pixels reach the panel before the whole frame is drawn.
+    /// The last band is shorter when the height is not a multiple of `band_height`.
+    ///
+    /// # Arguments
+    ///
+    /// * `band_height` - Rows per band. Zero is treated as one.
+    ///
+    /// # Returns
+    ///
+    /// An iterator over each band's `Region` and its pixel bytes.
+    pub fn bands(&mut self, band_height: u32) -> impl Iterator<Item = (Region, &mut [u8])> {
+        let band_height = band_height.max(1);
+        let width = self.width;
+        let stride = width as usize * BYTES_PER_PIXEL;
+        let len = (stride * self.height as usize).min(self.buffer.len());
+
+        self.buffer[..len]
+            .chunks_mut((stride * band_height as usize).max(1))
+            .enumerate()
+            .map(move |(index, band)| {
+                let region = Region {
+                    x: 0,
+                    y: (index as u32 * band_height) as u16,
+                    width,
+                    height: (band.len() / stride.max(1)) as u32,
+                };
+                (region, band)
+            })
+    }
+
+    /// Swaps the red and blue channels of every pixel.
+    ///
+    /// This converts assets authored for the opposite RGB/BGR color order without
+    /// rendering them again.
+    pub fn swap_rb(&mut self) {
+        self.map_pixels(Self::swap_rb_pixel);
+    }
+
+    /// Swaps the red and blue channels of every pixel in a region.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to convert. Parts outside the frame buffer are ignored.
+    pub fn swap_rb_region(&mut self, region: &Region) {
+        self.map_pixels_region(region, Self::swap_rb_pixel);
+    }
+
+    /// Darkens every pixel by scaling each channel by `factor / 255`.
+    ///
+    /// Dimming a copy of the screen by a decreasing factor over a few frames gives a
+    /// software fade without changing the panel's brightness.
+    ///
+    /// # Arguments
+    ///
+    /// * `factor` - Brightness to keep, from 0 (black) to 255 (unchanged).
+    pub fn dim(&mut self, factor: u8) {
+        self.map_pixels(|pixel| color::blend_rgb565(0, pixel, factor as u32, 255));
+    }
+
+    /// Darkens every pixel in a region by scaling each channel by `factor / 255`.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to dim. Parts outside the frame buffer are ignored.
+    /// * `factor` - Brightness to keep, from 0 (black) to 255 (unchanged).
+    pub fn dim_region(&mut self, region: &Region, factor: u8) {
+        self.map_pixels_region(region, |pixel| {
+            color::blend_rgb565(0, pixel, factor as u32, 255)
+        });
+    }
+
+    /// Replaces every pixel with the result of a function of its RGB565 value.
+    fn map_pixels(&mut self, f: impl Fn(u16) -> u16) {
+        for chunk in self.buffer.chunks_exact_mut(2) {
+            let pixel = f(u16::from_be_bytes([chunk[0], chunk[1]]));
+            chunk.copy_from_slice(&pixel.to_be_bytes());
+        }
+    }
+
+    /// Replaces every pixel in a region, clipped to the frame buffer, with the result of a
+    /// function of its RGB565 value.
+    fn map_pixels_region(&mut self, region: &Region, f: impl Fn(u16) -> u16) {
+        let start_x = (region.x as u32).min(self.width);
+        let end_x = (region.x as u32 + region.width).min(self.width);
+        let end_y = (region.y as u32 + region.height).min(self.height);
+
+        for y in region.y as u32..end_y {
+            let start = ((y * self.width + start_x) * 2) as usize;
+            let end = ((y * self.width + end_x) * 2) as usize;
+            for chunk in self.buffer[start..end].chunks_exact_mut(2) {
+                let pixel = f(u16::from_be_bytes([chunk[0], chunk[1]]));
+                chunk.copy_from_slice(&pixel.to_be_bytes());
+            }
+        }
+    }
+
+    /// Exchanges the 5-bit red and blue fields of an RGB565 pixel, keeping green.
+    fn swap_rb_pixel(pixel: u16) -> u16 {
+        (pixel & 0x07E0) | (pixel >> 11) | ((pixel & 0x1F) << 11)
+    }
+
+    /// Draws a sprite rotated around its center.
+    ///
+    /// Each destination pixel is mapped back into the sprite and takes the nearest source
+    /// pixel, so the rotated sprite has no holes. Pixels falling outside the frame buffer
+    /// are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `sprite` - Sprite pixels in RGB565 format, `sprite_w * sprite_h * 2` bytes.
+    /// * `sprite_w` - Width of the sprite.
+    /// * `sprite_h` - Height of the sprite.
+    /// * `center` - Where the sprite's center is placed in the frame buffer.
+    /// * `angle_deg` - Clockwise rotation in degrees.
+    /// * `transparent` - Color that is skipped instead of drawn.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>`, with `Error::BufferSize` if the sprite data is too short.
+    #[allow(clippy::too_many_arguments)]
+    pub fn draw_rotated_sprite(
+        &mut self,
+        sprite: &[u8],
+        sprite_w: u32,
+        sprite_h: u32,
+        center: Point,
+        angle_deg: f32,
+        transparent: Rgb565,
+    ) -> Result<(), Error> {
+        if (sprite.len() as u64) < sprite_w as u64 * sprite_h as u64 * 2 {
+            return Err(Error::BufferSize);
+        }
+
+        let angle = angle_deg.to_radians();
+        let (sin, cos) = (libm::sinf(angle), libm::cosf(angle));
+        let half_w = sprite_w as f32 / 2.0;
+        let half_h = sprite_h as f32 / 2.0;
+        let transparent = transparent.into_storage().to_be_bytes();
+
+        // Any rotation of the sprite fits in a square as wide as its diagonal
+        let reach = libm::ceilf(libm::sqrtf(half_w * half_w + half_h * half_h)) as i32;
+        let min_x = (center.x - reach).max(0);
+        let min_y = (center.y - reach).max(0);
+        let max_x = (center.x + reach).min(self.width as i32 - 1);
+        let max_y = (center.y + reach).min(self.height as i32 - 1);
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                // Rotate the destination pixel center back into sprite space
+                let dx = (x - center.x) as f32 + 0.5;
+                let dy = (y - center.y) as f32 + 0.5;
+                let sx = libm::floorf(dx * cos + dy * sin + half_w);
+                let sy = libm::floorf(-dx * sin + dy * cos + half_h);
+                if sx < 0.0 || sy < 0.0 || sx >= sprite_w as f32 || sy >= sprite_h as f32 {
+                    continue;
+                }
+
+                let src = (sy as usize * sprite_w as usize + sx as usize) * 2;
+                let pixel = [sprite[src], sprite[src + 1]];
+                if pixel == transparent {
+                    continue;
+                }
+
+                let dst = (y as usize * self.width as usize + x as usize) * 2;
+                self.buffer[dst..dst + 2].copy_from_slice(&pixel);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Draws a circle outline, optionally dashed.
+    ///
+    /// The ring's outer edge lies at `radius` and it extends `thickness` pixels inward.
+    /// Dashes are measured along the outer edge, clockwise from the 3 o'clock position.
+    /// Pixels outside the frame buffer are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - Center of the circle.
+    /// * `radius` - Outer radius of the ring.
+    /// * `thickness` - Width of the ring in pixels.
+    /// * `color` - Color of the ring.
+    /// * `dash` - Optional `(on, off)` arc lengths in pixels; `None` draws a solid ring.
+    pub fn draw_circle_outline(
+        &mut self,
+        center: Point,
+        radius: u16,
+        thickness: u16,
+        color: Rgb565,
+        dash: Option<(u16, u16)>,
+    ) {
+        if thickness == 0 {
+            return;
+        }
+
+        let bytes = color.into_storage().to_be_bytes();
+        // Squared distances of a full u16 radius overflow i32
+        let r = radius as i64;
+        let (center_x, center_y) = (center.x as i64, center.y as i64);
+
+        // Compare doubled distances so the ring edges fall on pixel centers
+        let outer = (2 * r + 1) * (2 * r + 1);
+        let inner = if thickness > radius {
+            0
+        } else {
+            let inner_r = r - thickness as i64;
+            (2 * inner_r + 1) * (2 * inner_r + 1)
+        };
+
+        let min_x = (center_x - r).max(0);
+        let min_y = (center_y - r).max(0);
+        let max_x = (center_x + r).min(self.width as i64 - 1);
+        let max_y = (center_y + r).min(self.height as i64 - 1);
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                let dx = x - center_x;
+                let dy = y - center_y;
+                let distance = 4 * (dx * dx + dy * dy);
+                if distance >= outer || (inner > 0 && distance < inner) {
+                    continue;
+                }
+
+                if let Some((on, off)) = dash {
+                    let period = on as f32 + off as f32;
+                    if period > 0.0 {
+                        let mut angle = libm::atan2f(dy as f32, dx as f32);
+                        if angle < 0.0 {
+                            angle += 2.0 * core::f32::consts::PI;
+                        }
+                        let arc = angle * radius as f32;
+                        if arc - libm::floorf(arc / period) * period >= on as f32 {
+                            continue;
+                        }
+                    }
+                }
+
+                let index = (y as usize * self.width as usize + x as usize) * 2;
+                self.buffer[index..index + 2].copy_from_slice(&bytes);
+            }
+        }
+    }
+
+    /// Fills a polygon, which may be concave or self-intersecting.
+    ///
+    /// Each row is filled with the even-odd rule, sampling at pixel centers, so a polygon
+    /// through integer corners covers exactly the pixels inside it. The edges crossing a
+    /// row are visited from left to right by finding the next crossing on each pass over
+    /// the edges, so no scratch storage is needed and any number of vertices can be used.
+    /// Pixels outside the frame buffer are clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The polygon's vertices, in order. Fewer than three points draw
+    ///   nothing.
+    /// * `color` - The fill color.
+    pub fn fill_polygon(&mut self, points: &[Point], color: Rgb565) {
+        if points.len() < 3 {
+            return;
+        }
+
+        let bytes = color.into_storage().to_be_bytes();
+
+        let (mut min_y, mut max_y) = (points[0].y, points[0].y);
+        for point in points.iter().skip(1) {
+            min_y = min_y.min(point.y);
+            max_y = max_y.max(point.y);
+        }
+        let min_y = min_y.max(0);
+        let max_y = max_y.min(self.height as i32 - 1);
+
+        for y in min_y..=max_y {
+            let sample_y = y as f32 + 0.5;
+            let row = y as usize * self.width as usize;
+
+            let mut inside = false;
+            let mut span_start = 0f32;
+            let mut last = f32::NEG_INFINITY;
+            loop {
+                // Find the leftmost crossing past the last one, and how many edges share it
+                let mut next = f32::INFINITY;
+                let mut count = 0;
+                let mut previous = points[points.len() - 1];
+                for &current in points {
+                    let (y0, y1) = (previous.y as f32, current.y as f32);
+                    if (y0 <= sample_y) != (y1 <= sample_y) {
+                        let t = (sample_y - y0) / (y1 - y0);
+                        let crossing = previous.x as f32 + t * (current.x - previous.x) as f32;
+                        if crossing > last {
+                            if crossing < next {
+                                next = crossing;
+                                count = 1;
+                            } else if crossing == next {
+                                count += 1;
+                            }
+                        }
+                    }
+                    previous = current;
+                }
+                if count == 0 {
+                    break;
+                }
+                last = next;
+
+                // An even number of edges meeting at one point leaves the parity unchanged
+                if count % 2 == 0 {
+                    continue;
+                }
+                if !inside {
+                    span_start = next;
+                    inside = true;
+                    continue;
+                }
+                inside = false;
+
+                // Fill the pixels whose centers lie between the pair of crossings
+                let start = (libm::floorf(span_start - 0.5) as i32 + 1).max(0);
+                let end = (libm::floorf(next - 0.5) as i32 + 1).min(self.width as i32);
+                for x in start..end {
+                    let index = (row + x as usize) * 2;
+                    self.buffer[index..index + 2].copy_from_slice(&bytes);
+                }
+            }
+        }
+    }
+
+    /// Blends a color into the pixel at the given coordinates.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - X-coordinate. Pixels outside the frame buffer are ignored.
+    /// * `y` - Y-coordinate.
+    /// * `color` - The color to blend in.
+    /// * `alpha` - Coverage of `color`, from 0 (unchanged) to 255 (replaced).
+    pub fn blend_pixel(&mut self, x: i32, y: i32, color: Rgb565, alpha: u8) {
+        if x < 0 || y < 0 || x as u32 >= self.width || y as u32 >= self.height {
+            return;
+        }
+
+        let index = (y as usize * self.width as usize + x as usize) * 2;
+        let background = u16::from_be_bytes([self.buffer[index], self.buffer[index + 1]]);
+        let blended = color::blend_rgb565(background, color.into_storage(), alpha as u32, 255);
+        self.buffer[index..index + 2].copy_from_slice(&blended.to_be_bytes());
+    }
+
+    /// Draws a one pixel wide antialiased line with Xiaolin Wu's algorithm.
+    ///
+    /// Each step along the major axis covers two pixels, blended with what is already in
+    /// the buffer in proportion to their distance from the ideal line. This smooths the
+    /// stair steps of a needle drawn at an angle. Pixels outside the frame buffer are
+    /// clipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `p0` - Start of the line.
+    /// * `p1` - End of the line.
+    /// * `color` - Color of the line.
+    pub fn draw_line_aa(&mut self, p0: Point, p1: Point, color: Rgb565) {
+        let steep = (p1.y - p0.y).abs() > (p1.x - p0.x).abs();
+        // Walk along x, swapping the axes for steep lines
+        let (mut x0, mut y0, mut x1, mut y1) = if steep {
+            (p0.y, p0.x, p1.y, p1.x)
+        } else {
+            (p0.x, p0.y, p1.x, p1.y)
+        };
+        if x0 > x1 {
+            core::mem::swap(&mut x0, &mut x1);
+            core::mem::swap(&mut y0, &mut y1);
+        }
+
+        let dx = (x1 - x0) as f32;
+        let gradient = if dx == 0.0 {
+            1.0
+        } else {
+            (y1 - y0) as f32 / dx
+        };
+
+        let mut y = y0 as f32;
+        for x in x0..=x1 {
+            let base = libm::floorf(y);
+            let fraction = y - base;
+            let far = (fraction * 255.0) as u8;
+            let near = 255 - far;
+            let base = base as i32;
+
+            if steep {
+                self.blend_pixel(base, x, color, near);
+                self.blend_pixel(base + 1, x, color, far);
+            } else {
+                self.blend_pixel(x, base, color, near);
+                self.blend_pixel(x, base + 1, color, far);
+            }
+            y += gradient;
+        }
+    }
+
+    /// Draws text from pre-rendered glyph tiles.
+    ///
+    /// Characters missing from the cache are skipped but still advance the position, so
+    /// the rest of the text stays aligned. Tiles are clipped to the frame buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The glyph cache to draw from.
+    /// * `text` - The text to draw.
+    /// * `position` - Top-left corner of the first character.
+    ///
+    /// # Returns
+    ///
+    /// The `Region` covered by the text, clipped to the frame buffer.
+    pub fn draw_cached_text(
+        &mut self,
+        cache: &GlyphCache<'_>,
+        text: &str,
+        position: Point,
+    ) -> Region {
+        let glyph = cache.glyph_size();
+        let advance = cache.advance() as i32;
+        let mut x = position.x;
+
+        for c in text.chars() {
+            if let Some(tile) = cache.tile(c) {
+                for row in 0..glyph.height as i32 {
+                    let y = position.y + row;
+                    if y < 0 || y >= self.height as i32 {
+                        continue;
+                    }
+
+                    // Clip the tile row horizontally
+                    let start = (-x).max(0);
+                    let end = (self.width as i32 - x).min(glyph.width as i32);
+                    if start >= end {
+                        continue;
+                    }
+
+                    let src = (row * glyph.width as i32 + start) as usize * 2;
+                    let dst = (y as usize * self.width as usize + (x + start) as usize) * 2;
+                    let len = (end - start) as usize * 2;
+                    self.buffer[dst..dst + len].copy_from_slice(&tile[src..src + len]);
+                }
+            }
+            x += advance;
+        }
+
+        // Report the area covered, clamped to the buffer
+        let left = position.x.max(0);
+        let top = position.y.max(0);
+        let right = (x - advance + glyph.width as i32).min(self.width as i32);
+        let bottom = (position.y + glyph.height as i32).min(self.height as i32);
+        if text.is_empty() || right <= left || bottom <= top {
+            return Region::default();
+        }
+
+        Region {
+            x: left as u16,
+            y: top as u16,
+            width: (right - left) as u32,
+            height: (bottom - top) as u32,
+        }
+    }
+
+    /// Draws text centered in a fixed region, on a filled background.
+    ///
+    /// The whole region is filled before the text is drawn, so a value whose number of
+    /// digits changes stays centered and leaves nothing of the previous value behind.
+    /// Text larger than the region is clipped to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to draw.
+    /// * `region` - The area to fill and center the text in.
+    /// * `font` - The font to draw with.
+    /// * `color` - Color of the text.
+    /// * `bg` - Color the region is filled with.
+    ///
+    /// # Returns
+    ///
+    /// The `Region` drawn, which is always `region`.
+    pub fn draw_text_centered(
+        &mut self,
+        text: &str,
+        region: Region,
+        font: &MonoFont<'_>,
+        color: Rgb565,
+        bg: Rgb565,
+    ) -> Region {
+        let size = geometry::text_region(text, font, Point::zero(), Baseline::Top);
+        let x = region.x as i32 + (region.width as i32 - size.width as i32) / 2;
+        let y = region.y as i32 + (region.height as i32 - size.height as i32) / 2;
+
+        let style = MonoTextStyleBuilder::new()
+            .font(font)
+            .text_color(color)
+            .build();
+        let mut target = Clipped::new(self, region);
+        // Drawing into a frame buffer cannot fail
+        let _ = target.clear(bg);
+        let _ = Text::with_baseline(text, Point::new(x, y), style, Baseline::Top).draw(&mut target);
+
+        region
+    }
+
+    /// Compares the current frame buffer with another frame buffer and returns an iterator
+    /// of `Pixel` that can be drawn to update the display.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other frame buffer to compare against.
+    ///
+    /// # Returns
+    ///
+    /// An iterator of `Pixel<Rgb565>`.
+    pub fn diff_with<'b>(
+        &'b self,
+        other: &'b FrameBuffer<'a>,
+    ) -> impl Iterator<Item = Pixel<Rgb565>> + 'b {
+        self.buffer
+            .chunks_exact(2)
+            .enumerate()
+            .filter_map(move |(i, chunk)| {
+                let other_chunk = &other.buffer[i * 2..i * 2 + 2];
+                if chunk != other_chunk {
+                    let x = (i as u32 % self.width) as i32;
+                    let y = (i as u32 / self.width) as i32;
+                    let raw_color = u16::from_be_bytes([chunk[0], chunk[1]]);
+                    let color = Rgb565::from(RawU16::new(raw_color));
+                    Some(Pixel(Point::new(x, y), color))
+                } else {
+                    None
+                }
+            })
+    }
+}
+
+impl<'a> DrawTarget for FrameBuffer<'a> {
+    type Color = Rgb565;
+    type Error = ();
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(coord, color) in pixels {
+            if coord.x >= 0
+                && coord.x < self.width as i32
+                && coord.y >= 0
+                && coord.y < self.height as i32
+            {
+                let index = ((coord.y as u32 * self.width + coord.x as u32) * 2) as usize;
+                let raw_color = color.into_storage();
+                self.buffer[index] = (raw_color >> 8) as u8;
+                self.buffer[index + 1] = raw_color as u8;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> OriginDimensions for FrameBuffer<'a> {
+    fn size(&self) -> Size {
         Size::new(self.width, self.height)
     }
-}
\ No newline at end of file
+}
+
+/// Compile-time check that the driver and buffers can be moved between cores.
+///
+/// None of the types keep shared state of their own, so they are `Send` whenever the bus
+/// and pins are, and frame buffers always are. Rendering on one core and calling `show`
+/// on another therefore needs no `unsafe`.
+#[allow(dead_code)]
+fn assert_send<SPI, DC, CS, RST>()
+where
+    SPI: SpiDevice + Send,
+    DC: OutputPin + Send,
+    CS: OutputPin + Send,
+    RST: OutputPin + Send,
+{
+    fn is_send<T: Send>() {}
+    is_send::<GC9A01A<SPI, DC, CS, RST>>();
+    is_send::<FrameBuffer<'static>>();
+    is_send::<PaletteFrameBuffer<'static>>();
+    is_send::<GlyphCache<'static>>();
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use core::convert::Infallible;
+    use embedded_hal::digital::ErrorType as PinErrorType;
+    use embedded_hal::spi::{ErrorKind, ErrorType as SpiErrorType, Operation};
+    use std::{cell::RefCell, rc::Rc, vec, vec::Vec};
+
+    /// Bus activity shared by the mock SPI device and pins.
+    #[derive(Default)]
+    struct Bus {
+        /// Level of the data/command pin.
+        dc: bool,
+        /// Level of the chip select pin.
+        cs: bool,
+        /// Bytes written, each with the data/command level it was sent at.
+        sent: Vec<(bool, u8)>,
+        /// Number of SPI writes attempted, including failed ones.
+        writes: usize,
+        /// Index of the SPI write that fails, if any.
+        fail_at: Option<usize>,
+        /// Responses returned by the next reads, in order; further reads return zeros.
+        responses: Vec<Vec<u8>>,
+    }
+
+    struct MockSpi(Rc<RefCell<Bus>>);
+
+    impl SpiErrorType for MockSpi {
+        type Error = ErrorKind;
+    }
+
+    impl SpiDevice for MockSpi {
+        fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), ErrorKind> {
+            let mut bus = self.0.borrow_mut();
+            for operation in operations {
+                match operation {
+                    Operation::Write(data) => {
+                        let index = bus.writes;
+                        bus.writes += 1;
+                        if bus.fail_at == Some(index) {
+                            return Err(ErrorKind::Other);
+                        }
+                        let dc = bus.dc;
+                        bus.sent.extend(data.iter().map(|&byte| (dc, byte)));
+                    }
+                    Operation::Read(buffer) => {
+                        buffer.fill(0);
+                        if !bus.responses.is_empty() {
+                            let response = bus.responses.remove(0);
+                            buffer[..response.len()].copy_from_slice(&response);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            Ok(())
+        }
+    }
+
+    impl SpiDevice<u16> for MockSpi {
+        fn transaction(&mut self, operations: &mut [Operation<'_, u16>]) -> Result<(), ErrorKind> {
+            let mut bus = self.0.borrow_mut();
+            for operation in operations {
+                if let Operation::Write(words) = operation {
+                    bus.writes += 1;
+                    let dc = bus.dc;
+                    bus.sent.extend(
+                        words
+                            .iter()
+                            .flat_map(|word| word.to_be_bytes())
+                            .map(|byte| (dc, byte)),
+                    );
+                }
+            }
+            Ok(())
+        }
+    }
+
+    /// Which line a mock pin drives.
+    #[derive(Clone, Copy)]
+    enum Line {
+        Dc,
+        Cs,
+        Rst,
+    }
+
+    struct MockPin(Rc<RefCell<Bus>>, Line);
+
+    impl PinErrorType for MockPin {
+        type Error = Infallible;
+    }
+
+    impl OutputPin for MockPin {
+        fn set_low(&mut self) -> Result<(), Infallible> {
+            self.set(false);
+            Ok(())
+        }
+
+        fn set_high(&mut self) -> Result<(), Infallible> {
+            self.set(true);
+            Ok(())
+        }
+    }
+
+    impl MockPin {
+        fn set(&mut self, level: bool) {
+            let mut bus = self.0.borrow_mut();
+            match self.1 {
+                Line::Dc => bus.dc = level,
+                Line::Cs => bus.cs = level,
+                Line::Rst => {}
+            }
+        }
+    }
+
+    struct NoDelay;
+
+    impl DelayNs for NoDelay {
+        fn delay_ns(&mut self, _ns: u32) {}
+    }
+
+    type MockDisplay = GC9A01A<MockSpi, MockPin, MockPin, MockPin>;
+
+    /// Creates a driver on a mock bus.
+    fn display(rgb: bool, width: u32, height: u32) -> (MockDisplay, Rc<RefCell<Bus>>) {
+        let bus = Rc::new(RefCell::new(Bus::default()));
+        let display = GC9A01A::new(
+            MockSpi(bus.clone()),
+            MockPin(bus.clone(), Line::Dc),
+            MockPin(bus.clone(), Line::Cs),
+            MockPin(bus.clone(), Line::Rst),
+            rgb,
+            width,
+            height,
+        );
+        (display, bus)
+    }
+
+    /// Creates an initialized driver on a mock bus, with the init traffic discarded.
+    fn initialized(rgb: bool, width: u32, height: u32) -> (MockDisplay, Rc<RefCell<Bus>>) {
+        let (mut display, bus) = display(rgb, width, height);
+        display.init(&mut NoDelay).unwrap();
+        bus.borrow_mut().sent.clear();
+        (display, bus)
+    }
+
+    /// Splits the bytes sent so far into commands and the data that followed each.
+    fn commands(bus: &Rc<RefCell<Bus>>) -> Vec<(u8, Vec<u8>)> {
+        let mut commands: Vec<(u8, Vec<u8>)> = Vec::new();
+        for &(dc, byte) in bus.borrow().sent.iter() {
+            match commands.last_mut() {
+                Some((_, data)) if dc => data.push(byte),
+                _ => commands.push((byte, Vec::new())),
+            }
+        }
+        commands
+    }
+
+    /// Returns the data sent with the last occurrence of a command.
+    fn last_params(bus: &Rc<RefCell<Bus>>, command: Instruction) -> Vec<u8> {
+        let command = command as u8;
+        commands(bus)
+            .into_iter()
+            .rev()
+            .find(|(sent, _)| *sent == command)
+            .map(|(_, data)| data)
+            .unwrap()
+    }
+
+    /// Runs `show_diff` from a black frame to one with a single changed pixel.
+    fn show_single_pixel_diff(display: &mut MockDisplay, x: usize, y: usize, color: u16) {
+        let mut old = vec![0u8; 240 * 240 * 2];
+        let mut new = old.clone();
+        let index = (y * 240 + x) * 2;
+        new[index..index + 2].copy_from_slice(&color.to_be_bytes());
+        display
+            .show_diff(
+                &FrameBuffer::new(&mut new, 240, 240),
+                &FrameBuffer::new(&mut old, 240, 240),
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn show_diff_sends_only_the_changed_run() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        show_single_pixel_diff(&mut display, 10, 5, 0xF81F);
+
+        assert_eq!(
+            commands(&bus),
+            vec![
+                (Instruction::CaSet as u8, vec![0, 10, 0, 10]),
+                (Instruction::RaSet as u8, vec![0, 5, 0, 5]),
+                (Instruction::RamWr as u8, vec![0xF8, 0x1F]),
+            ]
+        );
+        assert_eq!(bus.borrow().sent.len(), 13);
+    }
+
+    #[test]
+    fn show_diff_runs_use_software_brightness() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        display.set_software_brightness(0);
+        show_single_pixel_diff(&mut display, 10, 5, 0xFFFF);
+
+        assert_eq!(last_params(&bus, Instruction::RamWr), vec![0, 0]);
+    }
+
+    #[test]
+    fn show_diff_runs_use_write_continue() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        display.set_write_continue(true);
+        let mut old = vec![0u8; 240 * 240 * 2];
+        let mut new = old.clone();
+        // One run longer than the default chunk size
+        new[..80].fill(0xFF);
+        display
+            .show_diff(
+                &FrameBuffer::new(&mut new, 240, 240),
+                &FrameBuffer::new(&mut old, 240, 240),
+            )
+            .unwrap();
+
+        let sent = commands(&bus);
+        assert!(sent
+            .iter()
+            .any(|(command, _)| *command == Instruction::RamWrCont as u8));
+        let pixels: usize = sent
+            .iter()
+            .filter(|(command, _)| {
+                *command == Instruction::RamWr as u8 || *command == Instruction::RamWrCont as u8
+            })
+            .map(|(_, data)| data.len())
+            .sum();
+        assert_eq!(pixels, 80);
+    }
+
+    #[test]
+    fn show_diff_runs_update_the_mirror() {
+        let (mut display, _bus) = initialized(true, 240, 240);
+        display
+            .enable_mirror(vec![0u8; 240 * 240 * 2].leak())
+            .unwrap();
+        show_single_pixel_diff(&mut display, 10, 5, 0x1234);
+
+        let index = (5 * 240 + 10) * 2;
+        assert_eq!(display.snapshot()[index..index + 2], [0x12, 0x34]);
+        assert_eq!(display.snapshot().iter().filter(|&&b| b != 0).count(), 2);
+    }
+
+    /// Replays the memory writes sent so far into a model of the 240x240 frame memory.
+    fn panel_memory(bus: &Rc<RefCell<Bus>>) -> Vec<u8> {
+        let word = |data: &[u8], i: usize| u16::from_be_bytes([data[i], data[i + 1]]) as usize;
+        let mut memory = vec![0u8; 240 * 240 * 2];
+        let (mut columns, mut rows) = ((0, 0), (0, 0));
+        let mut position = 0;
+        for (command, data) in commands(bus) {
+            if command == Instruction::CaSet as u8 {
+                columns = (word(&data, 0), word(&data, 2));
+            } else if command == Instruction::RaSet as u8 {
+                rows = (word(&data, 0), word(&data, 2));
+            } else if command == Instruction::RamWr as u8 || command == Instruction::RamWrCont as u8
+            {
+                if command == Instruction::RamWr as u8 {
+                    position = 0;
+                }
+                let width = columns.1 - columns.0 + 1;
+                let pixels = width * (rows.1 - rows.0 + 1);
+                for byte in data {
+                    let pixel = position / 2 % pixels;
+                    let (x, y) = (columns.0 + pixel % width, rows.0 + pixel / width);
+                    memory[(y * 240 + x) * 2 + position % 2] = byte;
+                    position += 1;
+                }
+            }
+        }
+        memory
+    }
+
+    /// Runs a drawing operation with the mirror enabled and checks that the mirror
+    /// matches what was written to the panel.
+    fn assert_mirrored(name: &str, draw: impl FnOnce(&mut MockDisplay)) {
+        let (mut display, bus) = initialized(true, 240, 240);
+        display
+            .enable_mirror(vec![0u8; 240 * 240 * 2].leak())
+            .unwrap();
+        draw(&mut display);
+
+        let memory = panel_memory(&bus);
+        assert!(memory.iter().any(|&byte| byte != 0), "{name} drew nothing");
+        assert!(display.snapshot() == memory.as_slice(), "{name}");
+    }
+
+    #[test]
+    fn mirror_follows_every_drawing_path() {
+        use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+
+        let image: Vec<u8> = (0..240 * 240 * 2).map(|i| (i % 251) as u8 | 1).collect();
+        let pattern: Vec<u8> = (1..=8).collect();
+
+        assert_mirrored("show", |d| d.show(&image).unwrap());
+        assert_mirrored("show_region", |d| {
+            d.show_region(&image, 20, 30, 40, 5).unwrap()
+        });
+        assert_mirrored("fill_rect", |d| d.fill_rect(3, 4, 50, 20, 0xF81F).unwrap());
+        assert_mirrored("write_pixel", |d| d.write_pixel(3, 4, 0xF800).unwrap());
+        assert_mirrored("draw_iter", |d| {
+            Rectangle::new(Point::new(5, 6), Size::new(7, 3))
+                .into_styled(PrimitiveStyle::with_fill(Rgb565::GREEN))
+                .draw(d)
+                .unwrap()
+        });
+        assert_mirrored("draw_image", |d| d.draw_image(&image).unwrap());
+        assert_mirrored("draw_image_sized", |d| {
+            d.draw_image_sized(&image[..600], 100, 50, 30, 10).unwrap()
+        });
+        assert_mirrored("draw_colors", |d| {
+            let region = Region {
+                x: 10,
+                y: 10,
+                width: 9,
+                height: 4,
+            };
+            d.draw_colors(
+                region,
+                (0..36).map(|i| Rgb565::new(i % 32, 63, 31 - i % 32)),
+            )
+            .unwrap()
+        });
+        assert_mirrored("fill_tiled", |d| d.fill_tiled(&pattern, 2, 2).unwrap());
+        assert_mirrored("begin_pixels", |d| {
+            let region = Region {
+                x: 200,
+                y: 100,
+                width: 5,
+                height: 3,
+            };
+            let mut writer = d.begin_pixels(region).unwrap();
+            writer.push(&[0x1234; 7]).unwrap();
+            writer.push(&[0xABCD; 8]).unwrap();
+            writer.finish().unwrap();
+        });
+        assert_mirrored("show_from", |d| {
+            let region = Region {
+                x: 7,
+                y: 9,
+                width: 33,
+                height: 11,
+            };
+            d.show_from(&mut image.as_slice(), region).unwrap()
+        });
+        assert_mirrored("slide_in", |d| {
+            let mut buffer = vec![0x5Au8; 40 * 20 * 2];
+            let tile = FrameBuffer::new(&mut buffer, 40, 20);
+            d.slide_in(&tile, Edge::Left, 4, &mut NoDelay, 0).unwrap()
+        });
+        assert_mirrored("fade_to", |d| {
+            d.fade_to(&image, Rgb565::BLUE, 3, &mut NoDelay, 0).unwrap()
+        });
+        assert_mirrored("draw_image_rle", |d| {
+            let region = Region {
+                x: 30,
+                y: 40,
+                width: 10,
+                height: 2,
+            };
+            d.draw_image_rle(&region, &[12, 0xF8, 0x00, 8, 0x07, 0xE0])
+                .unwrap()
+        });
+        assert_mirrored("invert_region_on_panel", |d| {
+            let region = Region {
+                x: 50,
+                y: 60,
+                width: 8,
+                height: 3,
+            };
+            d.invert_region_on_panel(region).unwrap()
+        });
+        assert_mirrored("GC9A01ABatched", |d| {
+            let mut batched = GC9A01ABatched::new(d);
+            Rectangle::new(Point::new(15, 25), Size::new(12, 4))
+                .into_styled(PrimitiveStyle::with_fill(Rgb565::RED))
+                .draw(&mut batched)
+                .unwrap();
+            batched.flush().unwrap();
+        });
+        assert_mirrored("show_u16", |d| d.show_u16(&[0xBEEF; 240 * 240]).unwrap());
+        assert_mirrored("clear_screen_u16", |d| d.clear_screen_u16(0x0F0F).unwrap());
+    }
+
+    #[cfg(feature = "qoi")]
+    #[test]
+    fn mirror_follows_draw_qoi() {
+        // A 3x1 image: one red pixel and a run of two
+        let mut qoi = b"qoif".to_vec();
+        qoi.extend_from_slice(&3u32.to_be_bytes());
+        qoi.extend_from_slice(&1u32.to_be_bytes());
+        qoi.extend_from_slice(&[3, 0, 0xFE, 255, 0, 0, 0xC1]);
+        qoi.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]);
+        assert_mirrored("draw_qoi", |d| d.draw_qoi(&qoi).unwrap());
+    }
+
+    #[test]
+    fn mirror_keeps_pixels_before_software_brightness() {
+        let (mut display, _bus) = initialized(true, 240, 240);
+        display
+            .enable_mirror(vec![0u8; 240 * 240 * 2].leak())
+            .unwrap();
+        display.set_software_brightness(128);
+        let image = vec![0xFFu8; 240 * 240 * 2];
+        display.show(&image).unwrap();
+        assert!(display.snapshot() == image.as_slice());
+    }
+
+    #[test]
+    fn show_aborts_cleanly_on_spi_error() {
+        // Six writes: CaSet, its parameters, RaSet, its parameters, RamWr and the frame
+        for fail_at in 0..6 {
+            let (mut display, bus) = initialized(true, 240, 240);
+            let start = bus.borrow().writes;
+            bus.borrow_mut().fail_at = Some(start + fail_at);
+
+            let frame = vec![0u8; 240 * 240 * 2];
+            assert_eq!(display.show(&frame), Err(Error::Spi));
+
+            let bus = bus.borrow();
+            assert!(bus.cs, "chip select left asserted after write {fail_at}");
+            assert_eq!(bus.writes, start + fail_at + 1);
+        }
+    }
+
+    #[test]
+    fn offset_at_the_u16_boundary() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        display.set_offset(u16::MAX - 239, 0).unwrap();
+
+        display.set_address_window(239, 0, 239, 0).unwrap();
+        assert_eq!(
+            last_params(&bus, Instruction::CaSet),
+            vec![0xFF, 0xFF, 0xFF, 0xFF]
+        );
+
+        bus.borrow_mut().sent.clear();
+        assert_eq!(
+            display.set_address_window(240, 0, 240, 0),
+            Err(Error::OutOfBounds)
+        );
+        assert!(bus.borrow().sent.is_empty());
+    }
+
+    #[test]
+    fn set_offset_rejects_offsets_past_the_u16_boundary() {
+        let (mut display, _bus) = initialized(true, 240, 240);
+        assert_eq!(
+            display.set_offset(u16::MAX - 238, 0),
+            Err(Error::OutOfBounds)
+        );
+        assert_eq!(display.set_offset(0, u16::MAX), Err(Error::OutOfBounds));
+        assert_eq!(display.set_offset(u16::MAX - 239, u16::MAX - 239), Ok(()));
+    }
+
+    #[test]
+    fn empty_windows_send_nothing() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        let frame = vec![0u8; 240 * 240 * 2];
+        for (width, height) in [(0, 10), (10, 0)] {
+            let region = Region {
+                x: 10,
+                y: 20,
+                width,
+                height,
+            };
+            display.set_address_window_region(&region).unwrap();
+            display.show_region(&frame, 10, 20, width, height).unwrap();
+            display.store_region(region).unwrap();
+        }
+        display.set_address_window(10, 20, 9, 20).unwrap();
+        display.set_address_window(10, 20, 10, 19).unwrap();
+
+        assert!(display.get_regions().iter().all(Option::is_none));
+        display.show_regions(&frame).unwrap();
+        assert!(bus.borrow().sent.is_empty());
+    }
+
+    /// Returns where a frame memory address appears on the panel under a MADCTL value.
+    fn panel_position(madctl: u8, column: u16, row: u16) -> (u16, u16) {
+        let (x, y) = if madctl & MADCTL_MV != 0 {
+            (row, column)
+        } else {
+            (column, row)
+        };
+        let x = if madctl & MADCTL_MX != 0 {
+            RAM_SIZE - 1 - x
+        } else {
+            x
+        };
+        let y = if madctl & MADCTL_MY != 0 {
+            RAM_SIZE - 1 - y
+        } else {
+            y
+        };
+        (x, y)
+    }
+
+    #[test]
+    fn window_offset_maps_corners_in_every_orientation() {
+        // A 200x200 visible area at (10, 20) in frame memory
+        let (left, top, right, bottom) = (10, 20, 209, 219);
+        let corners = [(0, 0), (199, 0), (0, 199), (199, 199)];
+        let cases = [
+            (
+                Orientation::Portrait,
+                [(left, top), (right, top), (left, bottom), (right, bottom)],
+            ),
+            (
+                Orientation::Landscape,
+                [(right, top), (right, bottom), (left, top), (left, bottom)],
+            ),
+            (
+                Orientation::PortraitSwapped,
+                [(right, bottom), (left, bottom), (right, top), (left, top)],
+            ),
+            (
+                Orientation::LandscapeSwapped,
+                [(left, bottom), (left, top), (right, bottom), (right, top)],
+            ),
+        ];
+
+        for (orientation, expected) in cases {
+            let (mut display, bus) = initialized(true, 200, 200);
+            display.set_offset(10, 20).unwrap();
+            display.set_orientation(&orientation).unwrap();
+            let madctl = last_params(&bus, Instruction::MadCtl)[0];
+
+            for ((x, y), panel) in corners.into_iter().zip(expected) {
+                display.set_address_window(x, y, x, y).unwrap();
+                let column = last_params(&bus, Instruction::CaSet);
+                let row = last_params(&bus, Instruction::RaSet);
+                let column = u16::from_be_bytes([column[0], column[1]]);
+                let row = u16::from_be_bytes([row[0], row[1]]);
+                assert_eq!(
+                    panel_position(madctl, column, row),
+                    panel,
+                    "corner ({x}, {y}) in orientation {:#04x}",
+                    orientation as u8
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn fill_rect_rejects_windows_past_the_address_range() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        assert_eq!(
+            display.fill_rect(10, 0, u32::MAX, 1, 0),
+            Err(Error::OutOfBounds)
+        );
+        assert_eq!(
+            display.fill_rect(0, 1, 1, 65_536, 0),
+            Err(Error::OutOfBounds)
+        );
+        assert_eq!(
+            display.write_run(10, 0, u32::MAX, 0),
+            Err(Error::OutOfBounds)
+        );
+        assert!(bus.borrow().sent.is_empty());
+
+        display.fill_rect(0, 0, 65_536, 1, 0).unwrap();
+        assert_eq!(
+            last_params(&bus, Instruction::CaSet),
+            vec![0, 0, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn u16_shows_check_the_buffer_and_region() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        let short = vec![0u16; 240 * 240 - 1];
+        let frame = vec![0u16; 240 * 240];
+        let region = |x, width| Region {
+            x,
+            y: 0,
+            width,
+            height: 1,
+        };
+
+        assert_eq!(display.show_u16(&short), Err(Error::BufferSize));
+        assert_eq!(
+            display.show_region_u16(&short, region(0, 10)),
+            Err(Error::BufferSize)
+        );
+        assert_eq!(
+            display.show_region_u16(&frame, region(235, 10)),
+            Err(Error::OutOfBounds)
+        );
+        assert_eq!(
+            display.show_region_u16(&frame, region(10, u32::MAX)),
+            Err(Error::OutOfBounds)
+        );
+        assert!(bus.borrow().sent.is_empty());
+
+        display.show_region_u16(&frame, region(230, 10)).unwrap();
+        assert_eq!(last_params(&bus, Instruction::RamWr).len(), 20);
+    }
+
+    #[test]
+    fn show_from_rejects_regions_past_the_display() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        let frame = vec![0u8; 240 * 240 * 2];
+        let mut reader = frame.as_slice();
+        let region = |x, width| Region {
+            x,
+            y: 0,
+            width,
+            height: 2,
+        };
+
+        assert_eq!(
+            display.show_from(&mut reader, region(200, 41)),
+            Err(Error::OutOfBounds)
+        );
+        assert_eq!(
+            display.show_from(&mut reader, region(10, u32::MAX)),
+            Err(Error::OutOfBounds)
+        );
+        assert!(bus.borrow().sent.is_empty());
+
+        display.show_from(&mut reader, region(200, 40)).unwrap();
+        assert_eq!(last_params(&bus, Instruction::RamWr).len(), 160);
+    }
+
+    #[test]
+    fn init_sends_display_setup_between_the_vendor_blocks() {
+        let (mut display, bus) = display(true, 240, 240);
+        display.init(&mut NoDelay).unwrap();
+        let sent: Vec<u8> = commands(&bus)
+            .into_iter()
+            .map(|(command, _)| command)
+            .collect();
+
+        let setup = [
+            0x8F,
+            Instruction::DisSet5 as u8,
+            Instruction::MadCtl as u8,
+            Instruction::ColMod as u8,
+            0x90,
+        ];
+        assert!(sent.windows(setup.len()).any(|window| window == setup));
+        assert_eq!(sent.last(), Some(&(Instruction::DispOn as u8)));
+    }
+
+    #[test]
+    fn reset_and_init_reopens_the_memory_write() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        display.set_ram_write_continuation(true);
+        display.fill_rect(0, 0, 240, 240, 0).unwrap();
+        display.reset_and_init(&mut NoDelay).unwrap();
+        bus.borrow_mut().sent.clear();
+
+        display.fill_rect(0, 0, 240, 240, 0).unwrap();
+        let sent = commands(&bus);
+        assert!(sent
+            .iter()
+            .any(|(command, _)| *command == Instruction::CaSet as u8));
+        assert!(sent
+            .iter()
+            .any(|(command, _)| *command == Instruction::RamWr as u8));
+    }
+
+    /// Tests a pixel center against a polygon with the even-odd rule.
+    fn polygon_contains(points: &[Point], x: i32, y: i32) -> bool {
+        let (sample_x, sample_y) = (x as f32 + 0.5, y as f32 + 0.5);
+        let mut inside = false;
+        let mut previous = points[points.len() - 1];
+        for &current in points {
+            let (y0, y1) = (previous.y as f32, current.y as f32);
+            if (y0 <= sample_y) != (y1 <= sample_y) {
+                let t = (sample_y - y0) / (y1 - y0);
+                let crossing = previous.x as f32 + t * (current.x - previous.x) as f32;
+                if crossing < sample_x {
+                    inside = !inside;
+                }
+            }
+            previous = current;
+        }
+        inside
+    }
+
+    #[test]
+    fn fill_polygon_matches_the_even_odd_rule() {
+        let polygons: [&[Point]; 4] = [
+            // Axis-aligned square with integer corners
+            &[
+                Point::new(2, 3),
+                Point::new(12, 3),
+                Point::new(12, 9),
+                Point::new(2, 9),
+            ],
+            // Concave arrow reaching past the left and bottom edges
+            &[
+                Point::new(-5, 10),
+                Point::new(20, 0),
+                Point::new(12, 12),
+                Point::new(20, 40),
+            ],
+            // Self-intersecting star
+            &[
+                Point::new(16, 1),
+                Point::new(25, 30),
+                Point::new(1, 11),
+                Point::new(31, 11),
+                Point::new(7, 30),
+            ],
+            // Thin needle like the gauge draws
+            &[
+                Point::new(3, 28),
+                Point::new(27, 4),
+                Point::new(26, 6),
+                Point::new(4, 29),
+            ],
+        ];
+
+        for points in polygons {
+            assert_polygon_filled(points);
+        }
+    }
+
+    #[test]
+    fn draw_circle_outline_handles_the_largest_radius() {
+        let mut buffer = vec![0u8; 32 * 32 * 2];
+        let mut fb = FrameBuffer::new(&mut buffer, 32, 32);
+
+        // The whole buffer lies well inside the ring's inner edge
+        fb.draw_circle_outline(Point::new(16, 16), u16::MAX, 4, Rgb565::WHITE, None);
+        assert_eq!(fb.get_pixel(0, 0), Some(Rgb565::BLACK));
+
+        // A ring as thick as its radius is a filled disc
+        fb.draw_circle_outline(Point::new(16, 16), u16::MAX, u16::MAX, Rgb565::WHITE, None);
+        assert_eq!(fb.get_pixel(0, 0), Some(Rgb565::WHITE));
+        assert_eq!(fb.get_pixel(31, 31), Some(Rgb565::WHITE));
+
+        // The edge of a ring centered far outside the buffer crosses it
+        fb.clear(Rgb565::BLACK);
+        fb.draw_circle_outline(Point::new(65_551, 16), u16::MAX, 1, Rgb565::RED, None);
+        assert_eq!(fb.get_pixel(16, 16), Some(Rgb565::RED));
+        assert_eq!(fb.get_pixel(15, 16), Some(Rgb565::BLACK));
+    }
+
+    #[test]
+    fn fill_polygon_accepts_many_vertices() {
+        // Star with 100 points alternating between two radii
+        let points: Vec<Point> = (0..200)
+            .map(|i| {
+                let angle = i as f32 * core::f32::consts::PI / 100.0;
+                let radius = if i % 2 == 0 { 15.0 } else { 9.0 };
+                Point::new(
+                    16 + libm::roundf(radius * libm::cosf(angle)) as i32,
+                    16 + libm::roundf(radius * libm::sinf(angle)) as i32,
+                )
+            })
+            .collect();
+        assert_polygon_filled(&points);
+    }
+
+    /// Fills the polygon into a 32x32 buffer and checks every pixel against the
+    /// even-odd rule.
+    fn assert_polygon_filled(points: &[Point]) {
+        let mut buffer = vec![0u8; 32 * 32 * 2];
+        let mut fb = FrameBuffer::new(&mut buffer, 32, 32);
+        fb.fill_polygon(points, Rgb565::WHITE);
+
+        let mut filled = 0;
+        for y in 0..32 {
+            for x in 0..32 {
+                let expected = if polygon_contains(points, x, y) {
+                    filled += 1;
+                    Rgb565::WHITE
+                } else {
+                    Rgb565::BLACK
+                };
+                assert_eq!(
+                    fb.get_pixel(x as u16, y as u16),
+                    Some(expected),
+                    "({x}, {y})"
+                );
+            }
+        }
+        assert!(filled > 0);
+    }
+
+    #[test]
+    fn begin_pixels_on_an_empty_region_sends_nothing() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        let mut writer = display.begin_pixels(Region::default()).unwrap();
+        writer.push(&[0xFFFF; 300]).unwrap();
+        writer.finish().unwrap();
+        assert!(bus.borrow().sent.is_empty());
+    }
+
+    #[test]
+    fn is_ready_waits_out_transitions_across_clock_wrap() {
+        let (mut display, _bus) = initialized(true, 240, 240);
+        let start = u32::MAX - 1_000;
+        display.wake(start).unwrap();
+        assert!(!display.is_ready(start));
+        assert!(!display.is_ready(start.wrapping_add(SLEEP_OUT_SETTLE_US - 1)));
+        assert!(display.is_ready(start.wrapping_add(SLEEP_OUT_SETTLE_US)));
+
+        // Once settled, a wrapped-around clock does not make the wake pending again
+        assert!(display.is_ready(start));
+    }
+
+    #[test]
+    fn is_ready_is_not_cleared_by_commands_sent_while_settling() {
+        let (mut display, _bus) = initialized(true, 240, 240);
+        display.wake(0).unwrap();
+        display.set_orientation(&Orientation::Landscape).unwrap();
+        display.fill_rect(0, 0, 1, 1, 0).unwrap();
+        assert!(!display.is_ready(SLEEP_OUT_SETTLE_US - 1));
+        assert!(display.is_ready(SLEEP_OUT_SETTLE_US));
+
+        display.sleep(0).unwrap();
+        display.fill_rect(0, 0, 1, 1, 0).unwrap();
+        assert!(!display.is_ready(SLEEP_IN_SETTLE_US - 1));
+    }
+
+    #[test]
+    fn readback_probes_handle_the_circular_clip() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        display.set_circular_clip(true);
+
+        // The original pixel, then red in the first channel
+        bus.borrow_mut().responses = vec![vec![0; 4], vec![0, 0xF8, 0, 0]];
+        assert_eq!(display.probe_color_order(), Ok(true));
+        let writes: Vec<_> = commands(&bus)
+            .into_iter()
+            .filter(|(command, _)| *command == Instruction::RamWr as u8)
+            .map(|(_, data)| data)
+            .collect();
+        assert_eq!(writes, vec![vec![0xF8, 0x00], vec![0x00, 0x00]]);
+        assert_eq!(last_params(&bus, Instruction::CaSet), vec![0, 0, 0, 0]);
+
+        bus.borrow_mut().sent.clear();
+        assert_eq!(
+            display.write_pixel_verified(0, 0, 0xFFFF),
+            Err(Error::OutOfBounds)
+        );
+        assert!(bus.borrow().sent.is_empty());
+        assert_eq!(display.write_pixel_verified(120, 120, 0), Ok(true));
+    }
+
+    #[test]
+    fn orientations_after_init_send_the_preset_madctl() {
+        let cases = [
+            (Orientation::Portrait, 0x00),
+            (Orientation::Landscape, 0x60),
+            (Orientation::PortraitSwapped, 0xC0),
+            (Orientation::LandscapeSwapped, 0xA0),
+        ];
+        for (rgb, bgr) in [(true, 0x00), (false, 0x08)] {
+            for (orientation, expected) in cases {
+                let (mut display, bus) = initialized(rgb, 240, 240);
+                display.set_orientation(&orientation).unwrap();
+                assert_eq!(last_params(&bus, Instruction::MadCtl), vec![expected | bgr]);
+            }
+        }
+
+        // An explicit scan direction is kept across orientation changes
+        let (mut display, bus) = initialized(true, 240, 240);
+        display.set_scan_direction(false, true).unwrap();
+        display.set_orientation(&Orientation::Landscape).unwrap();
+        assert_eq!(last_params(&bus, Instruction::MadCtl), vec![0x70]);
+    }
+
+    #[test]
+    fn multi_display_keeps_drawing_settings_per_display() {
+        let bus = Rc::new(RefCell::new(Bus::default()));
+        let mut multi = MultiGC9A01A::new(
+            MockSpi(bus.clone()),
+            MockPin(bus.clone(), Line::Dc),
+            [
+                MockPin(bus.clone(), Line::Cs),
+                MockPin(bus.clone(), Line::Cs),
+            ],
+            MockPin(bus.clone(), Line::Rst),
+            true,
+            240,
+            240,
+        );
+        multi.select(1).unwrap();
+        multi.display().set_init_inversion(false);
+        multi.select(0).unwrap();
+        multi.init(&mut NoDelay).unwrap();
+        // Display 1 has its inversion turned back off after the broadcast init
+        assert_eq!(commands(&bus).last().unwrap().0, Instruction::InvOff as u8);
+
+        let display = multi.display();
+        display
+            .enable_mirror(vec![0u8; 240 * 240 * 2].leak())
+            .unwrap();
+        display.set_software_brightness(0);
+        display.set_circular_clip(true);
+
+        multi.select(1).unwrap();
+        bus.borrow_mut().sent.clear();
+        let display = multi.display();
+        assert!(display.snapshot().is_empty());
+        display.write_pixel(0, 0, 0xFFFF).unwrap();
+        assert_eq!(last_params(&bus, Instruction::RamWr), vec![0xFF, 0xFF]);
+
+        multi.select(0).unwrap();
+        bus.borrow_mut().sent.clear();
+        let display = multi.display();
+        assert_eq!(display.snapshot().len(), 240 * 240 * 2);
+        // Clipped, and then dimmed to black
+        display.write_pixel(0, 0, 0xFFFF).unwrap();
+        display.show_region(&[0xFF; 240 * 2], 120, 0, 1, 1).unwrap();
+        assert_eq!(last_params(&bus, Instruction::RamWr), vec![0x00, 0x00]);
+        assert_eq!(commands(&bus).len(), 3);
+    }
+
+    #[test]
+    fn fade_to_with_zero_steps_shows_the_target_color() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        display
+            .fade_to(&[0xFF; 240 * 240 * 2], Rgb565::BLUE, 0, &mut NoDelay, 0)
+            .unwrap();
+
+        let frames: Vec<_> = commands(&bus)
+            .into_iter()
+            .filter(|(command, _)| *command == Instruction::RamWr as u8)
+            .map(|(_, data)| data)
+            .collect();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].len(), 240 * 240 * 2);
+        assert!(frames[0].chunks_exact(2).all(|pixel| pixel == [0x00, 0x1F]));
+    }
+
+    #[test]
+    fn palette_flush_of_an_empty_buffer_sends_nothing() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        let fb = PaletteFrameBuffer::new(&mut [], 0, 10, [Rgb565::BLACK; 16]).unwrap();
+        fb.flush(&mut display).unwrap();
+        assert!(bus.borrow().sent.is_empty());
+    }
+
+    #[test]
+    fn draw_colors_rejects_an_overflowing_region() {
+        let (mut display, bus) = initialized(true, 240, 240);
+        let region = Region {
+            x: 0,
+            y: 0,
+            width: 1 << 16,
+            height: 1 << 16,
+        };
+        assert_eq!(
+            display.draw_colors(region, core::iter::repeat(Rgb565::RED)),
+            Err(Error::InvalidArgument)
+        );
+        assert!(bus.borrow().sent.is_empty());
+    }
+
+    #[test]
+    fn init_caches_madctl_for_scan_direction() {
+        for (rgb, expected) in [(true, 0x80), (false, 0x88)] {
+            let (mut display, bus) = initialized(rgb, 240, 240);
+            display.set_scan_direction(true, true).unwrap();
+            assert_eq!(last_params(&bus, Instruction::MadCtl), vec![expected]);
+        }
+    }
+}
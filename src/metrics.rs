@@ -0,0 +1,39 @@
+/// Durations of `show`/`show_region` calls collected by the driver.
+///
+/// All durations are in microseconds, as reported by the clock passed to
+/// `GC9A01A::with_clock`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TimingStats {
+    /// Number of recorded calls.
+    pub count: u32,
+    /// Shortest recorded call.
+    pub min_us: u32,
+    /// Longest recorded call.
+    pub max_us: u32,
+    /// Sum of all recorded calls.
+    pub total_us: u64,
+}
+
+impl TimingStats {
+    /// Returns the average duration, or 0 if nothing has been recorded.
+    pub fn average_us(&self) -> u32 {
+        if self.count == 0 {
+            0
+        } else {
+            (self.total_us / self.count as u64) as u32
+        }
+    }
+
+    /// Adds a duration to the statistics.
+    pub(crate) fn record(&mut self, duration_us: u32) {
+        if self.count == 0 {
+            self.min_us = duration_us;
+            self.max_us = duration_us;
+        } else {
+            self.min_us = self.min_us.min(duration_us);
+            self.max_us = self.max_us.max(duration_us);
+        }
+        self.count += 1;
+        self.total_us += duration_us as u64;
+    }
+}
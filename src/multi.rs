@@ -0,0 +1,283 @@
+use embedded_hal::delay::DelayNs;
+use embedded_hal::digital::{ErrorType, OutputPin};
+use embedded_hal::spi::SpiDevice;
+
+use core::mem;
+
+use crate::{Error, Region, GC9A01A, RAM_SIZE};
+
+/// A bank of chip select pins that acts as a single pin for the selected display.
+///
+/// When broadcasting, every pin in the bank is driven together so a command reaches all
+/// displays at once.
+pub struct ChipSelects<CS, const N: usize> {
+    /// Chip select pins, one per display.
+    pins: [CS; N],
+
+    /// Index of the selected display.
+    selected: usize,
+
+    /// Whether all pins are driven together.
+    broadcast: bool,
+}
+
+impl<CS, const N: usize> ErrorType for ChipSelects<CS, N>
+where
+    CS: OutputPin,
+{
+    type Error = CS::Error;
+}
+
+impl<CS, const N: usize> OutputPin for ChipSelects<CS, N>
+where
+    CS: OutputPin,
+{
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        if self.broadcast {
+            for pin in self.pins.iter_mut() {
+                pin.set_low()?;
+            }
+            Ok(())
+        } else {
+            self.pins[self.selected].set_low()
+        }
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        if self.broadcast {
+            for pin in self.pins.iter_mut() {
+                pin.set_high()?;
+            }
+            Ok(())
+        } else {
+            self.pins[self.selected].set_high()
+        }
+    }
+}
+
+/// State kept separately for each display.
+struct PanelState {
+    dx: u16,
+    dy: u16,
+    regions: [Option<Region>; 10],
+    region_tracking: bool,
+    initialized: bool,
+    madctl: u8,
+    scan_direction: u8,
+    inverted: bool,
+    init_inversion: bool,
+    settling: Option<(u32, u32)>,
+    ram_write_continuation: bool,
+    write_continue: bool,
+    software_brightness: u8,
+    chunk_size: usize,
+    circle_spans: Option<[(u16, u16); RAM_SIZE as usize]>,
+    mirror: Option<&'static mut [u8]>,
+}
+
+impl PanelState {
+    /// Captures the settings of a newly created driver, as the starting state of a display.
+    fn new<SPI, DC, CS, RST>(driver: &GC9A01A<SPI, DC, CS, RST>) -> Self
+    where
+        SPI: SpiDevice,
+        DC: OutputPin,
+        CS: OutputPin,
+        RST: OutputPin,
+    {
+        Self {
+            dx: driver.dx,
+            dy: driver.dy,
+            regions: driver.regions,
+            region_tracking: driver.region_tracking,
+            initialized: driver.initialized,
+            madctl: driver.madctl,
+            scan_direction: driver.scan_direction,
+            inverted: driver.inverted,
+            init_inversion: driver.init_inversion,
+            settling: driver.settling,
+            ram_write_continuation: driver.ram_write_continuation,
+            write_continue: driver.write_continue,
+            software_brightness: driver.software_brightness,
+            chunk_size: driver.chunk_size,
+            circle_spans: driver.circle_spans,
+            mirror: None,
+        }
+    }
+
+    /// Exchanges the settings held here with the driver's.
+    fn swap<SPI, DC, CS, RST>(&mut self, driver: &mut GC9A01A<SPI, DC, CS, RST>)
+    where
+        SPI: SpiDevice,
+        DC: OutputPin,
+        CS: OutputPin,
+        RST: OutputPin,
+    {
+        mem::swap(&mut self.dx, &mut driver.dx);
+        mem::swap(&mut self.dy, &mut driver.dy);
+        mem::swap(&mut self.regions, &mut driver.regions);
+        mem::swap(&mut self.region_tracking, &mut driver.region_tracking);
+        mem::swap(&mut self.initialized, &mut driver.initialized);
+        mem::swap(&mut self.madctl, &mut driver.madctl);
+        mem::swap(&mut self.scan_direction, &mut driver.scan_direction);
+        mem::swap(&mut self.inverted, &mut driver.inverted);
+        mem::swap(&mut self.init_inversion, &mut driver.init_inversion);
+        mem::swap(&mut self.settling, &mut driver.settling);
+        mem::swap(
+            &mut self.ram_write_continuation,
+            &mut driver.ram_write_continuation,
+        );
+        mem::swap(&mut self.write_continue, &mut driver.write_continue);
+        mem::swap(
+            &mut self.software_brightness,
+            &mut driver.software_brightness,
+        );
+        mem::swap(&mut self.chunk_size, &mut driver.chunk_size);
+        mem::swap(&mut self.circle_spans, &mut driver.circle_spans);
+        mem::swap(&mut self.mirror, &mut driver.mirror);
+    }
+}
+
+/// Driver for several GC9A01A displays sharing SPI, DC and RST with separate CS lines.
+///
+/// One display is selected at a time with `select`; `display` then gives access to a
+/// regular `GC9A01A` driver addressing only that display.
+///
+/// Everything that describes a display's content or how it is drawn is kept per display:
+/// offsets, orientation and scan direction, inversion and the inversion `init` applies,
+/// stored regions and region tracking, sleep transitions, the initialized flag, circular
+/// clipping, software brightness, chunk size, RamWrCont and RamWr continuation, and the
+/// mirror. The bus settings are shared: the color order and size given to `new`, the
+/// reset timing, the clock and clock adjuster, timing statistics and recording.
+pub struct MultiGC9A01A<SPI, DC, CS, RST, const N: usize>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    /// Driver for the currently selected display.
+    driver: GC9A01A<SPI, DC, ChipSelects<CS, N>, RST>,
+
+    /// Saved state for each display; the selected display's entry is unused.
+    states: [PanelState; N],
+}
+
+impl<SPI, DC, CS, RST, const N: usize> MultiGC9A01A<SPI, DC, CS, RST, N>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    /// Creates a new multi-display driver. The first display is selected.
+    ///
+    /// # Arguments
+    ///
+    /// * `spi` - Shared SPI interface.
+    /// * `dc` - Shared data/command pin.
+    /// * `cs` - Chip select pins, one per display.
+    /// * `rst` - Shared reset pin.
+    /// * `rgb` - Whether the displays are RGB (true) or BGR (false).
+    /// * `width` - Width of each display.
+    /// * `height` - Height of each display.
+    pub fn new(
+        spi: SPI,
+        dc: DC,
+        cs: [CS; N],
+        rst: RST,
+        rgb: bool,
+        width: u32,
+        height: u32,
+    ) -> Self {
+        let cs = ChipSelects {
+            pins: cs,
+            selected: 0,
+            broadcast: false,
+        };
+        let driver = GC9A01A::new(spi, dc, cs, rst, rgb, width, height);
+        let states = core::array::from_fn(|_| PanelState::new(&driver));
+
+        Self { driver, states }
+    }
+
+    /// Initializes all displays.
+    ///
+    /// The reset line is shared, so every display is reset and receives the init sequence
+    /// at the same time with all chip selects asserted. Displays whose init inversion
+    /// differs from the selected display's then have inversion set individually.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - Delay provider.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn init<DELAY>(&mut self, delay: &mut DELAY) -> Result<(), Error>
+    where
+        DELAY: DelayNs,
+    {
+        self.driver.cs.broadcast = true;
+        let result = self.driver.init(delay);
+        self.driver.cs.broadcast = false;
+        result?;
+
+        let selected = self.driver.cs.selected;
+        for (index, state) in self.states.iter_mut().enumerate() {
+            state.initialized = true;
+            state.inverted = self.driver.inverted;
+            state.madctl = self.driver.madctl;
+            state.scan_direction = self.driver.scan_direction;
+
+            if index != selected && state.init_inversion != self.driver.init_inversion {
+                let inverted = self.driver.inverted;
+                self.driver.cs.selected = index;
+                let result = self.driver.set_inversion(state.init_inversion);
+                self.driver.cs.selected = selected;
+                self.driver.inverted = inverted;
+                result?;
+                state.inverted = state.init_inversion;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Selects the display that subsequent operations address.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the display, in the order the chip select pins were given.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or `Error::InvalidSelection` if the index is
+    /// out of range.
+    pub fn select(&mut self, index: usize) -> Result<(), Error> {
+        if index >= N {
+            return Err(Error::InvalidSelection);
+        }
+
+        // Store the current display's settings, then load the next display's
+        let current = self.driver.cs.selected;
+        self.states[current].swap(&mut self.driver);
+        self.states[index].swap(&mut self.driver);
+        self.driver.cs.selected = index;
+        // The next display has its own window and has not just received RamWr
+        self.driver.window = None;
+        self.driver.mirror_cursor = None;
+        self.driver.ram_write_open = false;
+
+        Ok(())
+    }
+
+    /// Returns the index of the selected display.
+    pub fn selected(&self) -> usize {
+        self.driver.cs.selected
+    }
+
+    /// Returns the driver for the selected display.
+    pub fn display(&mut self) -> &mut GC9A01A<SPI, DC, ChipSelects<CS, N>, RST> {
+        &mut self.driver
+    }
+}
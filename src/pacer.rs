@@ -0,0 +1,83 @@
+use embedded_hal::delay::DelayNs;
+
+/// Caps the frame rate of a render loop without building up debt from slow frames.
+///
+/// Call `wait` once at the end of every frame. It sleeps for whatever is left of the frame
+/// budget; a frame that took longer than the budget is let through without sleeping, and
+/// the next frame gets a full budget instead of being shortened to catch up.
+pub struct AdaptivePacer {
+    /// Monotonic microsecond clock.
+    clock: fn() -> u32,
+
+    /// Duration of one frame at the target rate.
+    budget_us: u32,
+
+    /// Clock value at the start of the current frame.
+    frame_start: u32,
+
+    /// Duration of the last frame, including any sleep.
+    last_frame_us: u32,
+
+    /// Whether the last frame took longer than the budget.
+    overran: bool,
+}
+
+impl AdaptivePacer {
+    /// Creates a pacer and starts timing the first frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_fps` - Maximum frames per second. Zero is treated as one.
+    /// * `clock` - Function returning a monotonic time in microseconds. Wrapping is
+    ///   handled, so a free-running 32-bit counter can be used directly.
+    pub fn new(target_fps: u32, clock: fn() -> u32) -> Self {
+        Self {
+            clock,
+            budget_us: 1_000_000 / target_fps.max(1),
+            frame_start: clock(),
+            last_frame_us: 0,
+            overran: false,
+        }
+    }
+
+    /// Restarts timing of the current frame, for example after a pause.
+    pub fn reset(&mut self) {
+        self.frame_start = (self.clock)();
+    }
+
+    /// Sleeps for the rest of the frame budget and starts timing the next frame.
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - Delay provider.
+    pub fn wait<DELAY>(&mut self, delay: &mut DELAY)
+    where
+        DELAY: DelayNs,
+    {
+        let elapsed = (self.clock)().wrapping_sub(self.frame_start);
+        self.overran = elapsed > self.budget_us;
+        if !self.overran {
+            delay.delay_us(self.budget_us - elapsed);
+        }
+
+        // Start the next frame now rather than at the ideal time, so overruns are dropped
+        let now = (self.clock)();
+        self.last_frame_us = now.wrapping_sub(self.frame_start);
+        self.frame_start = now;
+    }
+
+    /// Returns the frame rate measured from the last frame, or 0 before the first `wait`.
+    pub fn fps(&self) -> u32 {
+        1_000_000u32.checked_div(self.last_frame_us).unwrap_or(0)
+    }
+
+    /// Returns the duration of the last frame in microseconds, including any sleep.
+    pub fn last_frame_us(&self) -> u32 {
+        self.last_frame_us
+    }
+
+    /// Returns whether the last frame took longer than the budget.
+    pub fn overran(&self) -> bool {
+        self.overran
+    }
+}
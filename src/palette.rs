@@ -0,0 +1,242 @@
+use embedded_graphics::{pixelcolor::Rgb565, prelude::*};
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, Instruction, GC9A01A};
+
+/// A frame buffer storing a 4-bit palette index per pixel.
+///
+/// Two pixels are packed per byte, the left pixel in the high nibble, so a 240x240 buffer
+/// needs 28800 bytes instead of 115200. Drawn colors are mapped to the nearest of the 16
+/// palette entries.
+pub struct PaletteFrameBuffer<'a> {
+    buffer: &'a mut [u8],
+    width: u32,
+    height: u32,
+    palette: [Rgb565; 16],
+}
+
+impl<'a> PaletteFrameBuffer<'a> {
+    /// Creates a new palette frame buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `buffer` - A mutable slice of at least `(width * height + 1) / 2` bytes.
+    /// * `width` - The width of the frame buffer.
+    /// * `height` - The height of the frame buffer.
+    /// * `palette` - The 16 colors the indices refer to.
+    ///
+    /// # Returns
+    ///
+    /// The frame buffer, or `Error::BufferSize` if the buffer is too small.
+    pub fn new(
+        buffer: &'a mut [u8],
+        width: u32,
+        height: u32,
+        palette: [Rgb565; 16],
+    ) -> Result<Self, Error> {
+        let pixels = (width as usize)
+            .checked_mul(height as usize)
+            .ok_or(Error::BufferSize)?;
+        if buffer.len() < pixels.div_ceil(2) {
+            return Err(Error::BufferSize);
+        }
+
+        Ok(Self {
+            buffer,
+            width,
+            height,
+            palette,
+        })
+    }
+
+    /// Builds a palette from the colors an image uses.
+    ///
+    /// The first 16 distinct colors are kept in order; unused entries are black.
+    ///
+    /// # Arguments
+    ///
+    /// * `colors` - The colors to include.
+    pub fn build_palette<I>(colors: I) -> [Rgb565; 16]
+    where
+        I: IntoIterator<Item = Rgb565>,
+    {
+        let mut palette = [Rgb565::BLACK; 16];
+        let mut len = 0;
+
+        for color in colors {
+            if len == palette.len() {
+                break;
+            }
+            if !palette[..len].contains(&color) {
+                palette[len] = color;
+                len += 1;
+            }
+        }
+
+        palette
+    }
+
+    /// Returns the palette.
+    pub fn palette(&self) -> &[Rgb565; 16] {
+        &self.palette
+    }
+
+    /// Replaces the palette. The stored indices are kept.
+    pub fn set_palette(&mut self, palette: [Rgb565; 16]) {
+        self.palette = palette;
+    }
+
+    /// Returns a reference to the packed index buffer.
+    pub fn get_buffer(&self) -> &[u8] {
+        self.buffer
+    }
+
+    /// Returns the palette index closest to a color.
+    fn nearest_index(&self, color: Rgb565) -> u8 {
+        let mut best = 0;
+        let mut best_distance = u32::MAX;
+
+        for (i, entry) in self.palette.iter().enumerate() {
+            // Red and blue have 5 bits, green 6, so scale red and blue to match green
+            let dr = (color.r() as i32 - entry.r() as i32) * 2;
+            let dg = color.g() as i32 - entry.g() as i32;
+            let db = (color.b() as i32 - entry.b() as i32) * 2;
+            let distance = (dr * dr + dg * dg + db * db) as u32;
+
+            if distance < best_distance {
+                best = i as u8;
+                best_distance = distance;
+                if distance == 0 {
+                    break;
+                }
+            }
+        }
+
+        best
+    }
+
+    /// Returns the palette index stored for a pixel.
+    fn index_at(&self, pixel: usize) -> u8 {
+        let byte = self.buffer[pixel / 2];
+        if pixel.is_multiple_of(2) {
+            byte >> 4
+        } else {
+            byte & 0x0F
+        }
+    }
+
+    /// Expands the indices to RGB565 and streams the whole buffer to the display.
+    ///
+    /// An empty buffer sends nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `display` - The display to send the frame to.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn flush<SPI, DC, CS, RST>(
+        &self,
+        display: &mut GC9A01A<SPI, DC, CS, RST>,
+    ) -> Result<(), Error>
+    where
+        SPI: SpiDevice,
+        DC: OutputPin,
+        CS: OutputPin,
+        RST: OutputPin,
+    {
+        display.ensure_initialized()?;
+        if self.width == 0 || self.height == 0 {
+            return Ok(());
+        }
+
+        display.set_address_window(0, 0, self.width as u16 - 1, self.height as u16 - 1)?;
+        display.write_command(Instruction::RamWr as u8, &[])?;
+        display.start_data()?;
+
+        // Pre-pack the palette so each pixel is a table lookup
+        let mut packed = [[0u8; 2]; 16];
+        for (bytes, color) in packed.iter_mut().zip(self.palette.iter()) {
+            *bytes = color.into_storage().to_be_bytes();
+        }
+
+        const CHUNK_SIZE: usize = 512;
+        let mut chunk = [0u8; CHUNK_SIZE];
+        let mut len = 0;
+
+        for pixel in 0..(self.width * self.height) as usize {
+            chunk[len..len + 2].copy_from_slice(&packed[self.index_at(pixel) as usize]);
+            len += 2;
+            if len == CHUNK_SIZE {
+                display.write_data(&chunk)?;
+                len = 0;
+            }
+        }
+        if len > 0 {
+            display.write_data(&chunk[..len])?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a> DrawTarget for PaletteFrameBuffer<'a> {
+    type Color = Rgb565;
+    type Error = ();
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        for Pixel(coord, color) in pixels {
+            if coord.x >= 0
+                && coord.x < self.width as i32
+                && coord.y >= 0
+                && coord.y < self.height as i32
+            {
+                let pixel = (coord.y as u32 * self.width + coord.x as u32) as usize;
+                let index = self.nearest_index(color);
+                let byte = &mut self.buffer[pixel / 2];
+                if pixel.is_multiple_of(2) {
+                    *byte = (*byte & 0x0F) | (index << 4);
+                } else {
+                    *byte = (*byte & 0xF0) | index;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'a> OriginDimensions for PaletteFrameBuffer<'a> {
+    fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_rejects_a_short_buffer() {
+        let palette = [Rgb565::BLACK; 16];
+        let mut buffer = [0u8; 4];
+        assert!(PaletteFrameBuffer::new(&mut buffer, 3, 3, palette).is_err());
+        assert!(PaletteFrameBuffer::new(&mut buffer, u32::MAX, u32::MAX, palette).is_err());
+        assert!(PaletteFrameBuffer::new(&mut buffer, 0, 0, palette).is_ok());
+    }
+
+    #[test]
+    fn odd_pixel_count_fits_in_rounded_up_buffer() {
+        let mut palette = [Rgb565::BLACK; 16];
+        palette[1] = Rgb565::WHITE;
+        let mut buffer = [0u8; 5];
+        let mut fb = PaletteFrameBuffer::new(&mut buffer, 3, 3, palette).unwrap();
+        fb.draw_iter([Pixel(Point::new(2, 2), Rgb565::WHITE)])
+            .unwrap();
+        assert_eq!(fb.get_buffer(), &[0, 0, 0, 0, 0x10]);
+    }
+}
@@ -0,0 +1,116 @@
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, GC9A01A};
+
+/// Number of bytes buffered before they are sent to the display.
+const CHUNK_SIZE: usize = 512;
+
+/// Streams pixels into an address window opened with `GC9A01A::begin_pixels`.
+///
+/// Pixels pushed across any number of calls are buffered and sent in chunks, without the
+/// window or RamWr being sent again. Buffered pixels are flushed when the writer is
+/// dropped; call `finish` instead to see any error from that last transfer.
+pub struct PixelWriter<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    /// Driver the window was opened on.
+    driver: &'a mut GC9A01A<SPI, DC, CS, RST>,
+
+    /// Pixels waiting to be sent, as big-endian RGB565.
+    chunk: [u8; CHUNK_SIZE],
+
+    /// Number of bytes used in `chunk`.
+    len: usize,
+
+    /// Whether the window is empty, so pushed pixels are dropped.
+    empty: bool,
+}
+
+impl<'a, SPI, DC, CS, RST> PixelWriter<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    /// Creates a writer for a window that has already been opened.
+    pub(crate) fn new(driver: &'a mut GC9A01A<SPI, DC, CS, RST>) -> Self {
+        Self {
+            driver,
+            chunk: [0; CHUNK_SIZE],
+            len: 0,
+            empty: false,
+        }
+    }
+
+    /// Creates a writer for an empty region, which sends nothing.
+    pub(crate) fn empty(driver: &'a mut GC9A01A<SPI, DC, CS, RST>) -> Self {
+        let mut writer = Self::new(driver);
+        writer.empty = true;
+        writer
+    }
+
+    /// Appends pixels to the window.
+    ///
+    /// # Arguments
+    ///
+    /// * `colors` - RGB565 pixel values, in window order.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn push(&mut self, colors: &[u16]) -> Result<(), Error> {
+        if self.empty {
+            return Ok(());
+        }
+        for color in colors {
+            self.chunk[self.len..self.len + 2].copy_from_slice(&color.to_be_bytes());
+            self.len += 2;
+            if self.len == CHUNK_SIZE {
+                self.flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends any buffered pixels to the display.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn flush(&mut self) -> Result<(), Error> {
+        if self.len > 0 {
+            let len = self.len;
+            self.len = 0;
+            self.driver.write_data(&self.chunk[..len])?;
+        }
+        Ok(())
+    }
+
+    /// Sends any buffered pixels and closes the writer.
+    ///
+    /// # Returns
+    ///
+    /// `Result<(), Error>` indicating success or failure.
+    pub fn finish(mut self) -> Result<(), Error> {
+        self.flush()
+    }
+}
+
+impl<'a, SPI, DC, CS, RST> Drop for PixelWriter<'a, SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    fn drop(&mut self) {
+        // Errors cannot be reported from drop; `finish` is there for callers who need them
+        let _ = self.flush();
+    }
+}
@@ -0,0 +1,136 @@
+//! Incremental decoder for QOI ("Quite OK Image") data.
+
+/// Size of the QOI header in bytes.
+const HEADER_SIZE: usize = 14;
+
+const OP_RGB: u8 = 0xFE;
+const OP_RGBA: u8 = 0xFF;
+const OP_INDEX: u8 = 0x00;
+const OP_DIFF: u8 = 0x40;
+const OP_LUMA: u8 = 0x80;
+const OP_RUN: u8 = 0xC0;
+const OP_MASK: u8 = 0xC0;
+
+/// Decodes the pixels of a QOI image one at a time, without an output buffer.
+pub(crate) struct QoiDecoder<'a> {
+    /// The encoded data, including the header.
+    data: &'a [u8],
+
+    /// Position of the next chunk in `data`.
+    pos: usize,
+
+    /// The previous pixel, as RGBA.
+    pixel: [u8; 4],
+
+    /// Recently seen pixels, indexed by their hash.
+    index: [[u8; 4]; 64],
+
+    /// Number of repeats of `pixel` left from a run.
+    run: u8,
+
+    /// Image width.
+    width: u32,
+
+    /// Image height.
+    height: u32,
+}
+
+impl<'a> QoiDecoder<'a> {
+    /// Reads the header of a QOI image.
+    ///
+    /// # Returns
+    ///
+    /// The decoder, or `None` if the data does not start with a valid QOI header.
+    pub(crate) fn new(data: &'a [u8]) -> Option<Self> {
+        let header = data.get(..HEADER_SIZE)?;
+        if &header[0..4] != b"qoif" || !matches!(header[12], 3 | 4) {
+            return None;
+        }
+
+        Some(Self {
+            data,
+            pos: HEADER_SIZE,
+            pixel: [0, 0, 0, 255],
+            index: [[0; 4]; 64],
+            run: 0,
+            width: u32::from_be_bytes([header[4], header[5], header[6], header[7]]),
+            height: u32::from_be_bytes([header[8], header[9], header[10], header[11]]),
+        })
+    }
+
+    /// Returns the image width from the header.
+    pub(crate) fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the image height from the header.
+    pub(crate) fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Decodes the next pixel.
+    ///
+    /// # Returns
+    ///
+    /// The pixel in RGB565 format, or `None` if the data ends early.
+    pub(crate) fn next_rgb565(&mut self) -> Option<u16> {
+        if self.run > 0 {
+            self.run -= 1;
+            return Some(self.rgb565());
+        }
+
+        let op = self.byte()?;
+        match op {
+            OP_RGB => {
+                self.pixel[0] = self.byte()?;
+                self.pixel[1] = self.byte()?;
+                self.pixel[2] = self.byte()?;
+            }
+            OP_RGBA => {
+                self.pixel[0] = self.byte()?;
+                self.pixel[1] = self.byte()?;
+                self.pixel[2] = self.byte()?;
+                self.pixel[3] = self.byte()?;
+            }
+            _ => match op & OP_MASK {
+                OP_INDEX => self.pixel = self.index[op as usize],
+                OP_DIFF => {
+                    self.pixel[0] = self.pixel[0].wrapping_add(((op >> 4) & 0x03).wrapping_sub(2));
+                    self.pixel[1] = self.pixel[1].wrapping_add(((op >> 2) & 0x03).wrapping_sub(2));
+                    self.pixel[2] = self.pixel[2].wrapping_add((op & 0x03).wrapping_sub(2));
+                }
+                OP_LUMA => {
+                    let next = self.byte()?;
+                    let dg = (op & 0x3F).wrapping_sub(32);
+                    let dr = dg.wrapping_add((next >> 4).wrapping_sub(8));
+                    let db = dg.wrapping_add((next & 0x0F).wrapping_sub(8));
+                    self.pixel[0] = self.pixel[0].wrapping_add(dr);
+                    self.pixel[1] = self.pixel[1].wrapping_add(dg);
+                    self.pixel[2] = self.pixel[2].wrapping_add(db);
+                }
+                // The previous pixel, plus `run` repeats
+                OP_RUN => self.run = op & 0x3F,
+                _ => unreachable!(),
+            },
+        }
+
+        let [r, g, b, a] = self.pixel;
+        let hash = (r as usize * 3 + g as usize * 5 + b as usize * 7 + a as usize * 11) % 64;
+        self.index[hash] = self.pixel;
+
+        Some(self.rgb565())
+    }
+
+    /// Reads the next byte of encoded data.
+    fn byte(&mut self) -> Option<u8> {
+        let byte = *self.data.get(self.pos)?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    /// Converts the current pixel to RGB565, ignoring alpha.
+    fn rgb565(&self) -> u16 {
+        let [r, g, b, _] = self.pixel;
+        ((r as u16 >> 3) << 11) | ((g as u16 >> 2) << 5) | (b as u16 >> 3)
+    }
+}
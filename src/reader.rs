@@ -0,0 +1,26 @@
+/// Source of frame data that cannot be borrowed as a slice, such as external PSRAM.
+///
+/// The frame is laid out like a `FrameBuffer`: rows of big-endian RGB565 pixels, each row
+/// spanning the full display width.
+pub trait FrameReader {
+    /// Error returned when a read fails.
+    type Error;
+
+    /// Reads `buf.len()` bytes of the frame starting at byte `offset`.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Byte offset into the frame.
+    /// * `buf` - Buffer to fill.
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error>;
+}
+
+impl FrameReader for &[u8] {
+    type Error = ();
+
+    fn read(&mut self, offset: usize, buf: &mut [u8]) -> Result<(), Self::Error> {
+        let src = self.get(offset..offset + buf.len()).ok_or(())?;
+        buf.copy_from_slice(src);
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use embedded_graphics::prelude::*;
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::SpiDevice;
+
+use crate::{Error, Orientation, GC9A01A};
+
+/// Common operations of round SPI display panels.
+///
+/// Code bounded on this trait instead of a concrete driver can work with any panel that
+/// implements it. The display size comes from the `OriginDimensions` supertrait.
+pub trait RoundDisplay: OriginDimensions {
+    /// Error returned by the display operations.
+    type Error;
+
+    /// Clears the screen by filling it with a single RGB565 color.
+    fn clear_screen(&mut self, color: u16) -> Result<(), Self::Error>;
+
+    /// Displays a full-screen RGB565 buffer.
+    fn show(&mut self, buffer: &[u8]) -> Result<(), Self::Error>;
+
+    /// Updates only the given region of the display from a full-screen RGB565 buffer.
+    fn show_region(
+        &mut self,
+        buffer: &[u8],
+        top_left_x: u16,
+        top_left_y: u16,
+        width: u32,
+        height: u32,
+    ) -> Result<(), Self::Error>;
+
+    /// Sets the orientation of the display.
+    fn set_orientation(&mut self, orientation: &Orientation) -> Result<(), Self::Error>;
+}
+
+impl<SPI, DC, CS, RST> RoundDisplay for GC9A01A<SPI, DC, CS, RST>
+where
+    SPI: SpiDevice,
+    DC: OutputPin,
+    CS: OutputPin,
+    RST: OutputPin,
+{
+    type Error = Error;
+
+    fn clear_screen(&mut self, color: u16) -> Result<(), Self::Error> {
+        GC9A01A::clear_screen(self, color)
+    }
+
+    fn show(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        GC9A01A::show(self, buffer)
+    }
+
+    fn show_region(
+        &mut self,
+        buffer: &[u8],
+        top_left_x: u16,
+        top_left_y: u16,
+        width: u32,
+        height: u32,
+    ) -> Result<(), Self::Error> {
+        GC9A01A::show_region(self, buffer, top_left_x, top_left_y, width, height)
+    }
+
+    fn set_orientation(&mut self, orientation: &Orientation) -> Result<(), Self::Error> {
+        GC9A01A::set_orientation(self, orientation)
+    }
+}